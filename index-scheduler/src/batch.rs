@@ -28,8 +28,8 @@ use meilisearch_types::heed::{RoTxn, RwTxn};
 use meilisearch_types::milli::documents::{obkv_to_object, DocumentsBatchReader};
 use meilisearch_types::milli::heed::CompactionOption;
 use meilisearch_types::milli::update::{
-    DocumentAdditionResult, DocumentDeletionResult, IndexDocumentsConfig, IndexDocumentsMethod,
-    Settings as MilliSettings,
+    enrich_documents_batch, DocumentAdditionResult, DocumentDeletionResult, IndexDocumentsConfig,
+    IndexDocumentsMethod, Settings as MilliSettings,
 };
 use meilisearch_types::milli::{self, BEU32};
 use meilisearch_types::settings::{apply_settings_to_builder, Settings, Unchecked};
@@ -42,7 +42,7 @@ use uuid::Uuid;
 
 use crate::autobatcher::{self, BatchKind};
 use crate::utils::{self, swap_index_uid_in_task};
-use crate::{Error, IndexScheduler, ProcessingTasks, Result, TaskId};
+use crate::{Error, IndexScheduler, ProcessingTasks, Result, TaskId, ALL_INDEXES_PAUSED};
 
 /// Represents a combination of tasks that can all be processed at the same time.
 ///
@@ -458,8 +458,35 @@ impl IndexScheduler {
             )));
         }
 
+        // The tasks above are not associated with any index and are never paused.
+        let paused = self.get_paused_indexes(rtxn)?;
+        if paused.contains(ALL_INDEXES_PAUSED) {
+            return Ok(None);
+        }
+
+        // We skip the tasks of the paused indexes.
+        let mut candidates = enqueued.clone();
+        for index in &paused {
+            candidates -= self.index_tasks(rtxn, index)?;
+        }
+
+        // A skipped index swap also blocks the tasks enqueued after it on the other swapped
+        // indexes, so that the tasks of an index are always processed in order.
+        let swaps = self.get_kind(rtxn, Kind::IndexSwap)?;
+        let mut skipped_swaps = (enqueued - &candidates) & &swaps;
+        while let Some(swap_id) = skipped_swaps.min() {
+            skipped_swaps.remove(swap_id);
+            let swap = self.get_task(rtxn, swap_id)?.ok_or(Error::CorruptedTaskQueue)?;
+            for index in swap.indexes() {
+                let mut blocked = self.index_tasks(rtxn, index)? & &candidates;
+                blocked.remove_range(..swap_id);
+                candidates -= &blocked;
+                skipped_swaps |= blocked & &swaps;
+            }
+        }
+
         // 5. We make a batch from the unprioritised tasks. Start by taking the next enqueued task.
-        let task_id = if let Some(task_id) = enqueued.min() { task_id } else { return Ok(None) };
+        let task_id = if let Some(task_id) = candidates.min() { task_id } else { return Ok(None) };
         let task = self.get_task(rtxn, task_id)?.ok_or(Error::CorruptedTaskQueue)?;
 
         // If the task is not associated with any index, verify that it is an index swap and
@@ -481,7 +508,7 @@ impl IndexScheduler {
             primary_key = index.primary_key(&rtxn)?.map(|pk| pk.to_string());
         }
 
-        let index_tasks = self.index_tasks(rtxn, index_name)? & enqueued;
+        let index_tasks = self.index_tasks(rtxn, index_name)? & candidates;
 
         // If autobatching is disabled we only take one task at a time.
         let tasks_limit = if self.autobatching_enabled { usize::MAX } else { 1 };
@@ -1026,6 +1053,56 @@ impl IndexScheduler {
                     }
                 }
 
+                // The documents whose external id is neither in the index nor in a previous task
+                // of the batch are new ones. A task that would bring the index above the limit
+                // fails without being indexed, and without making the other tasks of the batch fail.
+                let mut quota_errors: Vec<Option<Error>> = Vec::new();
+                if let Some(limit) = self.max_documents_per_index {
+                    let external_documents_ids = index.external_documents_ids(index_wtxn)?;
+                    let mut number_of_documents = index.number_of_documents(index_wtxn)?;
+                    let mut new_documents_ids = HashSet::new();
+                    for content_uuid in &content_files {
+                        let content_file = self.file_store.get_update(*content_uuid)?;
+                        let reader = DocumentsBatchReader::from_reader(content_file)
+                            .map_err(milli::Error::from)?;
+                        if reader.is_empty() {
+                            quota_errors.push(None);
+                            continue;
+                        }
+                        // a task containing invalid documents fails when it is indexed
+                        let reader = match enrich_documents_batch(index_wtxn, index, false, reader)?
+                        {
+                            Ok(reader) => reader,
+                            Err(_) => {
+                                quota_errors.push(None);
+                                continue;
+                            }
+                        };
+
+                        let mut task_new_documents_ids = HashSet::new();
+                        let (mut cursor, _) = reader.into_cursor_and_fields_index();
+                        while let Some(document) =
+                            cursor.next_enriched_document().map_err(milli::Error::from)?
+                        {
+                            let document_id = document.document_id.value();
+                            if external_documents_ids.get(document_id).is_none()
+                                && !new_documents_ids.contains(document_id)
+                            {
+                                task_new_documents_ids.insert(document_id.to_string());
+                            }
+                        }
+
+                        let current = number_of_documents + task_new_documents_ids.len() as u64;
+                        if current > limit {
+                            quota_errors.push(Some(Error::DocumentLimitReached { limit, current }));
+                        } else {
+                            number_of_documents = current;
+                            new_documents_ids.extend(task_new_documents_ids);
+                            quota_errors.push(None);
+                        }
+                    }
+                }
+
                 let config = IndexDocumentsConfig { update_method: method, ..Default::default() };
 
                 let mut builder = milli::update::IndexDocuments::new(
@@ -1038,7 +1115,12 @@ impl IndexScheduler {
                 )?;
 
                 let mut results = Vec::new();
-                for content_uuid in content_files.into_iter() {
+                for (i, content_uuid) in content_files.into_iter().enumerate() {
+                    if let Some(error) = quota_errors.get_mut(i).and_then(Option::take) {
+                        results.push(Err(error));
+                        continue;
+                    }
+
                     let content_file = self.file_store.get_update(content_uuid)?;
                     let reader = DocumentsBatchReader::from_reader(content_file)
                         .map_err(milli::Error::from)?;
@@ -1050,7 +1132,7 @@ impl IndexScheduler {
                             indexed_documents: count,
                             number_of_documents: count, // TODO: this is wrong, we should use the value stored in the Details.
                         }),
-                        Err(e) => Err(milli::Error::from(e)),
+                        Err(e) => Err(milli::Error::from(e).into()),
                     };
 
                     results.push(user_result);
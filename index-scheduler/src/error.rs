@@ -50,6 +50,10 @@ pub enum Error {
     IndexNotFound(String),
     #[error("Index `{0}` already exists.")]
     IndexAlreadyExists(String),
+    #[error("The maximum number of indexes, `{0}`, has been reached.")]
+    IndexLimitReached(usize),
+    #[error("The index would contain `{current}` documents, but the maximum number of documents per index is `{limit}`.")]
+    DocumentLimitReached { limit: u64, current: u64 },
     #[error(
         "Indexes must be declared only once during a swap. `{0}` was specified several times."
     )]
@@ -139,6 +143,8 @@ impl ErrorCode for Error {
         match self {
             Error::IndexNotFound(_) => Code::IndexNotFound,
             Error::IndexAlreadyExists(_) => Code::IndexAlreadyExists,
+            Error::IndexLimitReached(_) => Code::IndexLimitReached,
+            Error::DocumentLimitReached { .. } => Code::DocumentLimitReached,
             Error::SwapDuplicateIndexesFound(_) => Code::InvalidSwapDuplicateIndexFound,
             Error::SwapDuplicateIndexFound(_) => Code::InvalidSwapDuplicateIndexFound,
             Error::SwapIndexNotFound(_) => Code::IndexNotFound,
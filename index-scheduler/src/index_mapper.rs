@@ -7,6 +7,7 @@ use std::{fs, thread};
 use log::error;
 use meilisearch_types::heed::types::Str;
 use meilisearch_types::heed::{Database, Env, EnvOpenOptions, RoTxn, RwTxn};
+use meilisearch_types::index_uid_pattern::IndexUidPattern;
 use meilisearch_types::milli::update::IndexerConfig;
 use meilisearch_types::milli::Index;
 use time::OffsetDateTime;
@@ -36,6 +37,8 @@ pub struct IndexMapper {
     /// Path to the folder where the LMDB environments of each index are.
     base_path: PathBuf,
     index_size: usize,
+    /// The maximum number of indexes that can be created, if any.
+    pub(crate) max_indexes: Option<usize>,
     pub indexer_config: Arc<IndexerConfig>,
 }
 
@@ -54,6 +57,7 @@ impl IndexMapper {
         env: &Env,
         base_path: PathBuf,
         index_size: usize,
+        max_indexes: Option<usize>,
         indexer_config: IndexerConfig,
     ) -> Result<Self> {
         Ok(Self {
@@ -61,6 +65,7 @@ impl IndexMapper {
             index_mapping: env.create_database(Some(INDEX_MAPPING))?,
             base_path,
             index_size,
+            max_indexes,
             indexer_config: Arc::new(indexer_config),
         })
     }
@@ -96,7 +101,15 @@ impl IndexMapper {
                 Ok(index)
             }
             Err(Error::IndexNotFound(_)) => {
+                if let Some(max_indexes) = self.max_indexes {
+                    if self.index_mapping.len(&wtxn)? >= max_indexes as u64 {
+                        return Err(Error::IndexLimitReached(max_indexes));
+                    }
+                }
+
                 let uuid = Uuid::new_v4();
+                #[cfg(feature = "tracing")]
+                let _span = tracing::info_span!("index.create", index = name, %uuid).entered();
                 self.index_mapping.put(&mut wtxn, name, &uuid)?;
 
                 let index_path = self.base_path.join(uuid.to_string());
@@ -126,6 +139,9 @@ impl IndexMapper {
             .get(&wtxn, name)?
             .ok_or_else(|| Error::IndexNotFound(name.to_string()))?;
 
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("index.delete", index = name, %uuid).entered();
+
         // Once we retrieved the UUID of the index we remove it from the mapping table.
         assert!(self.index_mapping.delete(&mut wtxn, name)?);
 
@@ -167,6 +183,11 @@ impl IndexMapper {
         Ok(())
     }
 
+    /// Return the path to the folder containing the LMDB environment of the index, if it exists.
+    pub fn index_path(&self, rtxn: &RoTxn, name: &str) -> Result<Option<PathBuf>> {
+        Ok(self.index_mapping.get(rtxn, name)?.map(|uuid| self.base_path.join(uuid.to_string())))
+    }
+
     pub fn exists(&self, rtxn: &RoTxn, name: &str) -> Result<bool> {
         Ok(self.index_mapping.get(rtxn, name)?.is_some())
     }
@@ -194,6 +215,9 @@ impl IndexMapper {
                 // the entry method.
                 match index_map.entry(uuid) {
                     Entry::Vacant(entry) => {
+                        #[cfg(feature = "tracing")]
+                        let _span =
+                            tracing::info_span!("index.open", index = name, %uuid).entered();
                         let index_path = self.base_path.join(uuid.to_string());
 
                         let index = self.create_or_open_index(&index_path, None)?;
@@ -223,6 +247,43 @@ impl IndexMapper {
             .collect()
     }
 
+    /// Return the names of all the indexes without opening them.
+    pub fn index_names(&self, rtxn: &RoTxn) -> Result<Vec<String>> {
+        self.index_mapping
+            .iter(rtxn)?
+            .map(|ret| ret.map(|(name, _)| name.to_string()).map_err(Error::from))
+            .collect()
+    }
+
+    /// Return all the indexes whose name matches the pattern, may open them if they weren't already opened.
+    ///
+    /// The indexes that don't match the pattern are never opened.
+    pub fn indexes_matching(
+        &self,
+        rtxn: &RoTxn,
+        pattern: &IndexUidPattern,
+    ) -> Result<Vec<(String, Index)>> {
+        let prefix = match pattern.strip_suffix('*') {
+            Some(prefix) => prefix,
+            None => {
+                return match self.index(rtxn, pattern) {
+                    Ok(index) => Ok(vec![(pattern.to_string(), index)]),
+                    Err(Error::IndexNotFound(_)) => Ok(Vec::new()),
+                    Err(e) => Err(e),
+                }
+            }
+        };
+
+        self.index_mapping
+            .prefix_iter(rtxn, prefix)?
+            .map(|ret| {
+                ret.map_err(Error::from).and_then(|(name, _)| {
+                    self.index(rtxn, name).map(|index| (name.to_string(), index))
+                })
+            })
+            .collect()
+    }
+
     /// Swap two index names.
     pub fn swap(&self, wtxn: &mut RwTxn, lhs: &str, rhs: &str) -> Result<()> {
         let lhs_uuid = self
@@ -15,8 +15,11 @@ pub fn snapshot_index_scheduler(scheduler: &IndexScheduler) -> String {
 
     let IndexScheduler {
         autobatching_enabled,
+        slow_batch_threshold: _,
+        max_documents_per_index: _,
         must_stop_processing: _,
         processing_tasks,
+        tasks_status_changed: _,
         file_store,
         env,
         all_tasks,
@@ -27,6 +30,7 @@ pub fn snapshot_index_scheduler(scheduler: &IndexScheduler) -> String {
         enqueued_at,
         started_at,
         finished_at,
+        paused: _,
         index_mapper,
         wake_up: _,
         dumps_path: _,
@@ -30,24 +30,29 @@ mod uuid_codec;
 pub type Result<T> = std::result::Result<T, Error>;
 pub type TaskId = u32;
 
+use std::collections::{HashMap, HashSet};
 use std::ops::{Bound, RangeBounds};
 use std::path::PathBuf;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering::Relaxed;
-use std::sync::{Arc, RwLock};
-use std::time::Duration;
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::time::{Duration, Instant};
 
 use dump::{KindDump, TaskDump, UpdateFile};
 pub use error::Error;
 use file_store::FileStore;
 use meilisearch_types::error::ResponseError;
-use meilisearch_types::heed::types::{OwnedType, SerdeBincode, SerdeJson, Str};
-use meilisearch_types::heed::{self, Database, Env, RoTxn};
+use meilisearch_types::heed::types::{OwnedType, SerdeBincode, SerdeJson, Str, Unit};
+use meilisearch_types::heed::{self, Database, Env, RoTxn, RwTxn};
 use meilisearch_types::index_uid_pattern::IndexUidPattern;
 use meilisearch_types::milli;
 use meilisearch_types::milli::documents::DocumentsBatchBuilder;
 use meilisearch_types::milli::update::IndexerConfig;
-use meilisearch_types::milli::{CboRoaringBitmapCodec, Index, RoaringBitmapCodec, BEU32};
+use meilisearch_types::milli::{
+    CboRoaringBitmapCodec, Index, RoaringBitmapCodec, WarmUpReport, WarmUpTarget, BEU32,
+};
+use meilisearch_types::redacted::redacted;
+use meilisearch_types::settings::{Settings, Unchecked};
 use meilisearch_types::tasks::{Kind, KindWithContent, Status, Task};
 use roaring::RoaringBitmap;
 use synchronoise::SignalEvent;
@@ -136,6 +141,29 @@ impl Query {
     }
 }
 
+/// The outcome of [`IndexScheduler::flush`]: the status of the tasks that were
+/// enqueued or processing when the flush started.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct FlushReport {
+    pub succeeded: u64,
+    pub failed: u64,
+    pub canceled: u64,
+    /// The tasks that were not finished yet when the timeout elapsed.
+    pub pending: u64,
+}
+
+/// The indexes whose processing is paused, as returned by [`IndexScheduler::paused_indexes`].
+#[derive(Default, Debug, Clone)]
+pub struct PausedIndexes(HashSet<String>);
+
+impl PausedIndexes {
+    /// Return true iff the tasks of the given index are not processed, either because the
+    /// index or every index is paused. When `index` is `None`, only the latter is checked.
+    pub fn is_paused(&self, index: Option<&str>) -> bool {
+        self.0.contains(ALL_INDEXES_PAUSED) || index.map_or(false, |i| self.0.contains(i))
+    }
+}
+
 #[derive(Debug, Clone)]
 struct ProcessingTasks {
     /// The date and time at which the indexation started.
@@ -184,6 +212,33 @@ impl MustStopProcessing {
     }
 }
 
+/// Wakes up the threads waiting for the status of a task to change.
+///
+/// The mutex guards a counter which is incremented every time the run loop
+/// changes the status of some tasks.
+#[derive(Default, Clone, Debug)]
+struct TasksStatusChanged(Arc<(Mutex<u64>, Condvar)>);
+
+impl TasksStatusChanged {
+    fn generation(&self) -> u64 {
+        let (generation, _) = &*self.0;
+        *generation.lock().unwrap()
+    }
+
+    fn notify(&self) {
+        let (generation, condvar) = &*self.0;
+        *generation.lock().unwrap() += 1;
+        condvar.notify_all();
+    }
+
+    /// Block until a notification more recent than `generation` is sent or the timeout elapses.
+    fn wait_after(&self, generation: u64, timeout: Duration) {
+        let (current, condvar) = &*self.0;
+        let current = current.lock().unwrap();
+        let _ = condvar.wait_timeout_while(current, timeout, |current| *current == generation);
+    }
+}
+
 /// Database const names for the `IndexScheduler`.
 mod db_name {
     pub const ALL_TASKS: &str = "all-tasks";
@@ -194,8 +249,14 @@ mod db_name {
     pub const ENQUEUED_AT: &str = "enqueued-at";
     pub const STARTED_AT: &str = "started-at";
     pub const FINISHED_AT: &str = "finished-at";
+    pub const PAUSED: &str = "paused";
 }
 
+/// The key of the `paused` database pausing the processing of every index.
+///
+/// It can't collide with an index name as `*` is not allowed in an index uid.
+pub(crate) const ALL_INDEXES_PAUSED: &str = "*";
+
 #[cfg(test)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Breakpoint {
@@ -237,6 +298,12 @@ pub struct IndexSchedulerOptions {
     /// Set to `true` iff the index scheduler is allowed to automatically
     /// batch tasks together, to process multiple tasks at once.
     pub autobatching_enabled: bool,
+    /// Batches taking longer than this duration to process are logged as a warning.
+    pub slow_batch_threshold: Option<Duration>,
+    /// The maximum number of indexes that can be created, if any.
+    pub max_indexes: Option<usize>,
+    /// The maximum number of documents each index can contain, if any.
+    pub max_documents_per_index: Option<u64>,
 }
 
 /// Structure which holds meilisearch's indexes and schedules the tasks
@@ -251,6 +318,9 @@ pub struct IndexScheduler {
     /// The list of tasks currently processing
     pub(crate) processing_tasks: Arc<RwLock<ProcessingTasks>>,
 
+    /// Notified every time the status of some tasks changes.
+    pub(crate) tasks_status_changed: TasksStatusChanged,
+
     /// The list of files referenced by the tasks
     pub(crate) file_store: FileStore,
 
@@ -277,6 +347,9 @@ pub struct IndexScheduler {
     /// Store the task ids of tasks which finished at a specific date
     pub(crate) finished_at: Database<OwnedType<BEI128>, CboRoaringBitmapCodec>,
 
+    /// Store the indexes whose tasks must not be processed, see [`IndexScheduler::pause_processing`].
+    pub(crate) paused: Database<Str, Unit>,
+
     /// In charge of creating, opening, storing and returning indexes.
     pub(crate) index_mapper: IndexMapper,
 
@@ -286,6 +359,12 @@ pub struct IndexScheduler {
     /// Whether auto-batching is enabled or not.
     pub(crate) autobatching_enabled: bool,
 
+    /// The duration above which a batch is logged as slow, if any.
+    pub(crate) slow_batch_threshold: Option<Duration>,
+
+    /// The maximum number of documents each index can contain, if any.
+    pub(crate) max_documents_per_index: Option<u64>,
+
     /// The path used to create the dumps.
     pub(crate) dumps_path: PathBuf,
 
@@ -323,6 +402,7 @@ impl IndexScheduler {
             env: self.env.clone(),
             must_stop_processing: self.must_stop_processing.clone(),
             processing_tasks: self.processing_tasks.clone(),
+            tasks_status_changed: self.tasks_status_changed.clone(),
             file_store: self.file_store.clone(),
             all_tasks: self.all_tasks,
             status: self.status,
@@ -332,9 +412,12 @@ impl IndexScheduler {
             enqueued_at: self.enqueued_at,
             started_at: self.started_at,
             finished_at: self.finished_at,
+            paused: self.paused,
             index_mapper: self.index_mapper.clone(),
             wake_up: self.wake_up.clone(),
             autobatching_enabled: self.autobatching_enabled,
+            slow_batch_threshold: self.slow_batch_threshold,
+            max_documents_per_index: self.max_documents_per_index,
             snapshots_path: self.snapshots_path.clone(),
             dumps_path: self.dumps_path.clone(),
             auth_path: self.auth_path.clone(),
@@ -371,6 +454,7 @@ impl IndexScheduler {
         let this = Self {
             must_stop_processing: MustStopProcessing::default(),
             processing_tasks: Arc::new(RwLock::new(ProcessingTasks::new())),
+            tasks_status_changed: TasksStatusChanged::default(),
             file_store,
             all_tasks: env.create_database(Some(db_name::ALL_TASKS))?,
             status: env.create_database(Some(db_name::STATUS))?,
@@ -380,16 +464,20 @@ impl IndexScheduler {
             enqueued_at: env.create_database(Some(db_name::ENQUEUED_AT))?,
             started_at: env.create_database(Some(db_name::STARTED_AT))?,
             finished_at: env.create_database(Some(db_name::FINISHED_AT))?,
+            paused: env.create_database(Some(db_name::PAUSED))?,
             index_mapper: IndexMapper::new(
                 &env,
                 options.indexes_path,
                 options.index_size,
+                options.max_indexes,
                 options.indexer_config,
             )?,
             env,
             // we want to start the loop right away in case meilisearch was ctrl+Ced while processing things
             wake_up: Arc::new(SignalEvent::auto(true)),
             autobatching_enabled: options.autobatching_enabled,
+            slow_batch_threshold: options.slow_batch_threshold,
+            max_documents_per_index: options.max_documents_per_index,
             dumps_path: options.dumps_path,
             snapshots_path: options.snapshots_path,
             auth_path: options.auth_path,
@@ -430,7 +518,7 @@ impl IndexScheduler {
                         Ok(0) => (),
                         Ok(_) => run.wake_up.signal(),
                         Err(e) => {
-                            log::error!("{}", e);
+                            log::error!("{}", redacted(&e));
                             // Wait one second when an irrecoverable error occurs.
                             if matches!(
                                 e,
@@ -457,6 +545,16 @@ impl IndexScheduler {
         Ok(self.env.real_disk_size()?)
     }
 
+    /// The maximum number of indexes that can be created, if any.
+    pub fn max_indexes(&self) -> Option<usize> {
+        self.index_mapper.max_indexes
+    }
+
+    /// The maximum number of documents each index can contain, if any.
+    pub fn max_documents_per_index(&self) -> Option<u64> {
+        self.max_documents_per_index
+    }
+
     /// Return the index corresponding to the name.
     ///
     /// * If the index wasn't opened before, the index will be opened.
@@ -466,12 +564,49 @@ impl IndexScheduler {
         self.index_mapper.index(&rtxn, name)
     }
 
+    /// Return the path to the folder containing the LMDB environment of the index.
+    ///
+    /// Returns `None` if the index doesn't exist. The index isn't opened.
+    pub fn index_path(&self, name: &str) -> Result<Option<PathBuf>> {
+        let rtxn = self.env.read_txn()?;
+        self.index_mapper.index_path(&rtxn, name)
+    }
+
     /// Return and open all the indexes.
     pub fn indexes(&self) -> Result<Vec<(String, Index)>> {
         let rtxn = self.env.read_txn()?;
         self.index_mapper.indexes(&rtxn)
     }
 
+    /// Return the names of all the indexes without opening them.
+    pub fn index_names(&self) -> Result<Vec<String>> {
+        let rtxn = self.env.read_txn()?;
+        self.index_mapper.index_names(&rtxn)
+    }
+
+    /// Return and open all the indexes whose name matches the pattern.
+    pub fn indexes_matching(&self, pattern: &IndexUidPattern) -> Result<Vec<(String, Index)>> {
+        let rtxn = self.env.read_txn()?;
+        self.index_mapper.indexes_matching(&rtxn, pattern)
+    }
+
+    /// Load the selected databases of the index in the page cache, see [`Index::warm_up`].
+    ///
+    /// The warm-up is interrupted once the deadline, if any, is reached.
+    pub fn warm_up(
+        &self,
+        name: &str,
+        targets: &[WarmUpTarget],
+        deadline: Option<Instant>,
+    ) -> Result<WarmUpReport> {
+        let index = self.index(name)?;
+        let rtxn = index.read_txn()?;
+        let report = index.warm_up(&rtxn, targets, || {
+            deadline.map_or(false, |deadline| Instant::now() >= deadline)
+        })?;
+        Ok(report)
+    }
+
     /// Return the task ids matched by the given query from the index scheduler's point of view.
     pub(crate) fn get_task_ids(&self, rtxn: &RoTxn, query: &Query) -> Result<RoaringBitmap> {
         let ProcessingTasks {
@@ -619,6 +754,46 @@ impl IndexScheduler {
         Ok(nbr_index_processing_tasks > 0)
     }
 
+    /// Return the number of tasks associated with this index for each status.
+    ///
+    /// The counts are computed from the status and index bitmaps only, the tasks
+    /// themselves are never deserialized.
+    pub fn index_task_counts_by_status(&self, index: &str) -> Result<HashMap<Status, u64>> {
+        let rtxn = self.env.read_txn()?;
+        let processing_tasks = self.processing_tasks.read().unwrap().processing.clone();
+        let index_tasks = self.index_tasks(&rtxn, index)?;
+
+        let mut counts = HashMap::new();
+        for status in enum_iterator::all::<Status>() {
+            let count = match status {
+                // the processing tasks are only known in memory
+                Status::Processing => processing_tasks.intersection_len(&index_tasks),
+                // and they are still registered as enqueued in the status database
+                Status::Enqueued => (self.get_status(&rtxn, status)? - &processing_tasks)
+                    .intersection_len(&index_tasks),
+                status => self.get_status(&rtxn, status)?.intersection_len(&index_tasks),
+            };
+            counts.insert(status, count);
+        }
+
+        Ok(counts)
+    }
+
+    /// Return the number of tasks associated with this index for each kind of task.
+    ///
+    /// Like [`Self::index_task_counts_by_status`], only the bitmaps are read.
+    pub fn index_task_counts_by_kind(&self, index: &str) -> Result<HashMap<Kind, u64>> {
+        let rtxn = self.env.read_txn()?;
+        let index_tasks = self.index_tasks(&rtxn, index)?;
+
+        let mut counts = HashMap::new();
+        for kind in enum_iterator::all::<Kind>() {
+            counts.insert(kind, self.get_kind(&rtxn, kind)?.intersection_len(&index_tasks));
+        }
+
+        Ok(counts)
+    }
+
     /// Return the task ids matching the query from the user's point of view.
     ///
     /// There are two differences between an internal query and a query executed by
@@ -699,14 +874,194 @@ impl IndexScheduler {
         }
     }
 
+    /// Return the task once its status differs from `known_status`.
+    ///
+    /// If the status of the task already differs from `known_status` the task is returned
+    /// right away. Otherwise the call blocks until the status changes or the timeout elapses
+    /// and then returns the latest version of the task.
+    pub fn wait_task_status_change(
+        &self,
+        task_id: TaskId,
+        known_status: Status,
+        timeout: Duration,
+    ) -> Result<Task> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            // We must read the generation before the task, otherwise we could
+            // miss a notification sent between the two reads.
+            let generation = self.tasks_status_changed.generation();
+            let query = Query { uids: Some(vec![task_id]), ..Query::default() };
+            let task = self
+                .get_tasks_from_authorized_indexes(query, None)?
+                .pop()
+                .ok_or(Error::TaskNotFound(task_id))?;
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if task.status != known_status || remaining.is_zero() {
+                return Ok(task);
+            }
+            self.tasks_status_changed.wait_after(generation, remaining);
+        }
+    }
+
+    /// Block until all the tasks that are enqueued or processing when this method is
+    /// called are finished, or until the timeout elapses.
+    ///
+    /// Only the tasks of the given index are waited for when `index` is set. The tasks
+    /// registered while waiting are ignored, and tasks that get deleted in the meantime
+    /// are not counted.
+    pub fn flush(&self, index: Option<&str>, timeout: Duration) -> Result<FlushReport> {
+        let deadline = Instant::now() + timeout;
+        let rtxn = self.env.read_txn()?;
+        // the processing tasks are still registered as enqueued in the status database
+        let mut tasks = self.get_status(&rtxn, Status::Enqueued)?;
+        if let Some(index) = index {
+            tasks &= self.index_tasks(&rtxn, index)?;
+        }
+        drop(rtxn);
+
+        loop {
+            // We must read the generation before the statuses, otherwise we could
+            // miss a notification sent between the two reads.
+            let generation = self.tasks_status_changed.generation();
+            let rtxn = self.env.read_txn()?;
+            let report = FlushReport {
+                succeeded: self.get_status(&rtxn, Status::Succeeded)?.intersection_len(&tasks),
+                failed: self.get_status(&rtxn, Status::Failed)?.intersection_len(&tasks),
+                canceled: self.get_status(&rtxn, Status::Canceled)?.intersection_len(&tasks),
+                pending: self.get_status(&rtxn, Status::Enqueued)?.intersection_len(&tasks),
+            };
+            drop(rtxn);
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if report.pending == 0 || remaining.is_zero() {
+                return Ok(report);
+            }
+            self.tasks_status_changed.wait_after(generation, remaining);
+        }
+    }
+
+    /// Stop processing the tasks of the given index, or of every index when `index` is `None`.
+    ///
+    /// The new tasks are still registered but stay enqueued, and the batch currently
+    /// processing, if any, is left to finish. The tasks that are not associated with any
+    /// index, such as dumps and snapshots, are never paused. The flag is persisted and
+    /// survives a restart.
+    pub fn pause_processing(&self, index: Option<&str>) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        self.paused.put(&mut wtxn, index.unwrap_or(ALL_INDEXES_PAUSED), &())?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    /// Resume the processing paused by [`Self::pause_processing`] with the same `index`.
+    pub fn resume_processing(&self, index: Option<&str>) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        self.paused.delete(&mut wtxn, index.unwrap_or(ALL_INDEXES_PAUSED))?;
+        wtxn.commit()?;
+        // the enqueued tasks were skipped while paused and must be picked up now
+        self.wake_up.signal();
+        Ok(())
+    }
+
+    /// Return true iff the tasks of the given index are not processed, either because the
+    /// index or every index is paused. When `index` is `None`, only the latter is checked.
+    pub fn is_processing_paused(&self, index: Option<&str>) -> Result<bool> {
+        Ok(self.paused_indexes()?.is_paused(index))
+    }
+
+    /// Return the paused indexes, to check many indexes without reading the database each time.
+    pub fn paused_indexes(&self) -> Result<PausedIndexes> {
+        let rtxn = self.env.read_txn()?;
+        Ok(PausedIndexes(self.get_paused_indexes(&rtxn)?))
+    }
+
     /// Register a new task in the scheduler.
     ///
     /// If it fails and data was associated with the task, it tries to delete the associated data.
     pub fn register(&self, kind: KindWithContent) -> Result<Task> {
         let mut wtxn = self.env.write_txn()?;
+        let task = self.register_in_wtxn(&mut wtxn, kind)?;
+
+        if let Err(e) = wtxn.commit() {
+            self.delete_persisted_task_data(&task)?;
+            return Err(e.into());
+        }
+
+        self.after_registration(&task);
+
+        Ok(task)
+    }
 
+    /// Register all the given tasks in the scheduler in a single transaction.
+    ///
+    /// The tasks get consecutive uids, in the given order, and no other task
+    /// can be enqueued in between. Either all of them are registered or none is.
+    ///
+    /// If it fails, it tries to delete the data associated with every given task.
+    pub fn register_many(&self, kinds: Vec<KindWithContent>) -> Result<Vec<Task>> {
+        let content_files: Vec<_> = kinds.iter().filter_map(|kind| kind.content_uuid()).collect();
+
+        let result = self.env.write_txn().map_err(Error::from).and_then(|mut wtxn| {
+            let tasks = kinds
+                .into_iter()
+                .map(|kind| self.register_in_wtxn(&mut wtxn, kind))
+                .collect::<Result<Vec<_>>>()?;
+            wtxn.commit()?;
+            Ok(tasks)
+        });
+
+        match result {
+            Ok(tasks) => {
+                for task in &tasks {
+                    self.after_registration(task);
+                }
+                Ok(tasks)
+            }
+            Err(e) => {
+                for content_file in content_files {
+                    if let Err(e) = self.delete_update_file(content_file) {
+                        log::error!(
+                            "Failure to delete the content file {content_file}. Error: {e}"
+                        );
+                    }
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Register one settings update for each of the given indexes in a single transaction.
+    ///
+    /// No index can be created or deleted in between the updates, see [`Self::register_many`].
+    /// The updates are registered in the alphabetical order of the indexes.
+    pub fn register_settings_updates(
+        &self,
+        updates: HashMap<String, Settings<Unchecked>>,
+        allow_index_creation: bool,
+    ) -> Result<HashMap<String, Task>> {
+        let mut updates: Vec<_> = updates.into_iter().collect();
+        updates.sort_unstable_by(|(left, _), (right, _)| left.cmp(right));
+
+        let index_uids: Vec<_> = updates.iter().map(|(index_uid, _)| index_uid.clone()).collect();
+        let kinds = updates
+            .into_iter()
+            .map(|(index_uid, new_settings)| KindWithContent::SettingsUpdate {
+                index_uid,
+                new_settings: Box::new(new_settings),
+                is_deletion: false,
+                allow_index_creation,
+            })
+            .collect();
+
+        let tasks = self.register_many(kinds)?;
+        Ok(index_uids.into_iter().zip(tasks).collect())
+    }
+
+    /// Write a new enqueued task in the given transaction.
+    fn register_in_wtxn(&self, wtxn: &mut RwTxn, kind: KindWithContent) -> Result<Task> {
         let mut task = Task {
-            uid: self.next_task_id(&wtxn)?,
+            uid: self.next_task_id(wtxn)?,
             enqueued_at: OffsetDateTime::now_utc(),
             started_at: None,
             finished_at: None,
@@ -714,7 +1069,7 @@ impl IndexScheduler {
             canceled_by: None,
             details: kind.default_details(),
             status: Status::Enqueued,
-            kind: kind.clone(),
+            kind,
         };
         // For deletion and cancelation tasks, we want to make extra sure that they
         // don't attempt to delete/cancel tasks that are newer than themselves.
@@ -726,43 +1081,51 @@ impl IndexScheduler {
         // Get rid of the mutability.
         let task = task;
 
-        self.all_tasks.append(&mut wtxn, &BEU32::new(task.uid), &task)?;
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "update.enqueue",
+            task = task.uid,
+            index = ?task.indexes(),
+            kind = ?task.kind.as_kind(),
+            payload_size = task
+                .content_uuid()
+                .and_then(|uuid| self.file_store.compute_size(uuid).ok()),
+        )
+        .entered();
+
+        self.all_tasks.append(wtxn, &BEU32::new(task.uid), &task)?;
 
         for index in task.indexes() {
-            self.update_index(&mut wtxn, index, |bitmap| {
+            self.update_index(wtxn, index, |bitmap| {
                 bitmap.insert(task.uid);
             })?;
         }
 
-        self.update_status(&mut wtxn, Status::Enqueued, |bitmap| {
+        self.update_status(wtxn, Status::Enqueued, |bitmap| {
             bitmap.insert(task.uid);
         })?;
 
-        self.update_kind(&mut wtxn, task.kind.as_kind(), |bitmap| {
+        self.update_kind(wtxn, task.kind.as_kind(), |bitmap| {
             bitmap.insert(task.uid);
         })?;
 
-        utils::insert_task_datetime(&mut wtxn, self.enqueued_at, task.enqueued_at, task.uid)?;
+        utils::insert_task_datetime(wtxn, self.enqueued_at, task.enqueued_at, task.uid)?;
 
-        if let Err(e) = wtxn.commit() {
-            self.delete_persisted_task_data(&task)?;
-            return Err(e.into());
-        }
+        Ok(task)
+    }
 
+    /// Must be called once a registered task has been committed.
+    fn after_registration(&self, task: &Task) {
         // If the registered task is a task cancelation
         // we inform the processing tasks to stop (if necessary).
-        if let KindWithContent::TaskCancelation { tasks, .. } = kind {
-            let tasks_to_cancel = RoaringBitmap::from_iter(tasks);
-            if self.processing_tasks.read().unwrap().must_cancel_processing_tasks(&tasks_to_cancel)
-            {
+        if let KindWithContent::TaskCancelation { tasks, .. } = &task.kind {
+            if self.processing_tasks.read().unwrap().must_cancel_processing_tasks(tasks) {
                 self.must_stop_processing.must_stop();
             }
         }
 
         // notify the scheduler loop to execute a new tick
         self.wake_up.signal();
-
-        Ok(task)
     }
 
     /// Register a new task comming from a dump in the scheduler.
@@ -948,10 +1311,21 @@ impl IndexScheduler {
         let processed_tasks = ids.len();
         let processing_tasks = RoaringBitmap::from_sorted_iter(ids.iter().copied()).unwrap();
         let started_at = OffsetDateTime::now_utc();
+        let processing_time = Instant::now();
+
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "update.process",
+            tasks = ?ids,
+            duration_ms = tracing::field::Empty,
+            result = tracing::field::Empty,
+        )
+        .entered();
 
         // We reset the must_stop flag to be sure that we don't stop processing tasks
         self.must_stop_processing.reset();
         self.processing_tasks.write().unwrap().start_processing_at(started_at, processing_tasks);
+        self.tasks_status_changed.notify();
 
         #[cfg(test)]
         self.breakpoint(Breakpoint::BatchCreated);
@@ -996,6 +1370,8 @@ impl IndexScheduler {
                     }
                 }
                 log::info!("A batch of tasks was successfully completed.");
+                #[cfg(feature = "tracing")]
+                span.record("result", "succeeded");
             }
             // If we have an abortion error we must stop the tick here and re-schedule tasks.
             Err(Error::Milli(milli::Error::InternalError(
@@ -1003,6 +1379,8 @@ impl IndexScheduler {
             ))) => {
                 #[cfg(test)]
                 self.breakpoint(Breakpoint::AbortedIndexation);
+                #[cfg(feature = "tracing")]
+                span.record("result", "aborted");
                 wtxn.abort().map_err(Error::HeedTransaction)?;
 
                 // We make sure that we don't call `stop_processing` on the `processing_tasks`,
@@ -1016,6 +1394,15 @@ impl IndexScheduler {
             Err(err) => {
                 #[cfg(test)]
                 self.breakpoint(Breakpoint::ProcessBatchFailed);
+                #[cfg(feature = "tracing")]
+                {
+                    span.record("result", "failed");
+                    tracing::error!(
+                        code = %meilisearch_types::error::ErrorCode::error_name(&err),
+                        error = %err,
+                        "A batch of tasks failed."
+                    );
+                }
                 let error: ResponseError = err.into();
                 for id in ids {
                     let mut task = self
@@ -1046,6 +1433,17 @@ impl IndexScheduler {
         self.maybe_fail(tests::FailureLocation::CommittingWtxn)?;
 
         wtxn.commit().map_err(Error::HeedTransaction)?;
+        self.tasks_status_changed.notify();
+
+        let elapsed = processing_time.elapsed();
+        #[cfg(feature = "tracing")]
+        span.record("duration_ms", elapsed.as_millis() as u64);
+        if self.slow_batch_threshold.map_or(false, |threshold| elapsed > threshold) {
+            log::warn!(
+                "A batch of {processed_tasks} tasks took {}ms to process.",
+                elapsed.as_millis()
+            );
+        }
 
         #[cfg(test)]
         self.breakpoint(Breakpoint::AfterProcessing);
@@ -1087,6 +1485,7 @@ impl IndexScheduler {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
     use std::io::{BufWriter, Seek, Write};
     use std::time::Instant;
 
@@ -1099,6 +1498,7 @@ mod tests {
     use meilisearch_types::milli::update::IndexDocumentsMethod::{
         ReplaceDocuments, UpdateDocuments,
     };
+    use meilisearch_types::milli::update::Setting;
     use meilisearch_types::tasks::IndexSwap;
     use meilisearch_types::VERSION_FILE_NAME;
     use tempfile::{NamedTempFile, TempDir};
@@ -1124,11 +1524,20 @@ mod tests {
         pub fn test(
             autobatching_enabled: bool,
             planned_failures: Vec<(usize, FailureLocation)>,
+        ) -> (Self, IndexSchedulerHandle) {
+            Self::test_with_custom_config(planned_failures, |options| {
+                options.autobatching_enabled = autobatching_enabled;
+            })
+        }
+
+        pub fn test_with_custom_config(
+            planned_failures: Vec<(usize, FailureLocation)>,
+            configuration: impl Fn(&mut IndexSchedulerOptions),
         ) -> (Self, IndexSchedulerHandle) {
             let tempdir = TempDir::new().unwrap();
             let (sender, receiver) = crossbeam::channel::bounded(0);
 
-            let options = IndexSchedulerOptions {
+            let mut options = IndexSchedulerOptions {
                 version_file_path: tempdir.path().join(VERSION_FILE_NAME),
                 auth_path: tempdir.path().join("auth"),
                 tasks_path: tempdir.path().join("db_path"),
@@ -1139,8 +1548,12 @@ mod tests {
                 task_db_size: 1000 * 1000, // 1 MB, we don't use MiB on purpose.
                 index_size: 1000 * 1000,   // 1 MB, we don't use MiB on purpose.
                 indexer_config: IndexerConfig::default(),
-                autobatching_enabled,
+                autobatching_enabled: true,
+                slow_batch_threshold: None,
+                max_indexes: None,
+                max_documents_per_index: None,
             };
+            configuration(&mut options);
 
             let index_scheduler = Self::new(options, sender, planned_failures).unwrap();
 
@@ -1217,7 +1630,7 @@ mod tests {
         let mut buffer = BufWriter::new(temp_file.reopen().unwrap());
         buffer.write_all(bytes).unwrap();
         buffer.flush().unwrap();
-        meilisearch_types::document_formats::read_json(temp_file.as_file(), write)
+        meilisearch_types::document_formats::read_json(temp_file.as_file(), write, None)
     }
 
     /// Create an update file with the given file uuid.
@@ -1342,6 +1755,18 @@ mod tests {
             self.advance_till([AfterProcessing]);
         }
 
+        /// Finish processing a batch containing an index creation once it has been created.
+        #[track_caller]
+        fn finish_index_creation_batch(&mut self) {
+            // an index creation calls `process_batch` a second time to set the primary key
+            self.advance_till([
+                InsideProcessBatch,
+                InsideProcessBatch,
+                ProcessBatchSucceeded,
+                AfterProcessing,
+            ]);
+        }
+
         // Wait for one failed batch.
         #[track_caller]
         fn advance_one_failed_batch(&mut self) {
@@ -3419,4 +3844,519 @@ mod tests {
         // No matter what happens in process_batch, the index_scheduler should be internally consistent
         snapshot!(snapshot_index_scheduler(&index_scheduler), name: "index_creation_failed");
     }
+
+    #[test]
+    fn index_task_counts_by_status() {
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+        index_scheduler.register(index_creation_task("catto", "mouse")).unwrap();
+        index_scheduler.register(index_creation_task("doggo", "bone")).unwrap();
+        index_scheduler.register(index_creation_task("catto", "mouse")).unwrap();
+
+        let counts = index_scheduler.index_task_counts_by_status("catto").unwrap();
+        assert_eq!(counts[&Status::Enqueued], 2);
+        assert_eq!(counts[&Status::Processing], 0);
+
+        // the first index creation is now processing
+        handle.advance_till([Start, BatchCreated]);
+        let counts = index_scheduler.index_task_counts_by_status("catto").unwrap();
+        assert_eq!(counts[&Status::Enqueued], 1);
+        assert_eq!(counts[&Status::Processing], 1);
+        let counts = index_scheduler.index_task_counts_by_status("doggo").unwrap();
+        assert_eq!(counts[&Status::Enqueued], 1);
+        assert_eq!(counts[&Status::Processing], 0);
+
+        handle.finish_index_creation_batch();
+        handle.advance_one_successful_batch();
+        // the second creation of `catto` fails because the index already exists
+        handle.advance_one_failed_batch();
+
+        let counts = index_scheduler.index_task_counts_by_status("catto").unwrap();
+        assert_eq!(counts[&Status::Enqueued], 0);
+        assert_eq!(counts[&Status::Processing], 0);
+        assert_eq!(counts[&Status::Succeeded], 1);
+        assert_eq!(counts[&Status::Failed], 1);
+        assert_eq!(counts[&Status::Canceled], 0);
+        let counts = index_scheduler.index_task_counts_by_status("doggo").unwrap();
+        assert_eq!(counts[&Status::Succeeded], 1);
+
+        // an unknown index has no tasks
+        let counts = index_scheduler.index_task_counts_by_status("whalo").unwrap();
+        assert!(counts.values().all(|count| *count == 0));
+    }
+
+    #[test]
+    fn index_task_counts_by_kind() {
+        let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
+
+        index_scheduler.register(index_creation_task("catto", "mouse")).unwrap();
+        index_scheduler.register(replace_document_import_task("catto", None, 0, 12)).unwrap();
+        index_scheduler.register(replace_document_import_task("catto", None, 1, 50)).unwrap();
+        index_scheduler.register(index_creation_task("doggo", "bone")).unwrap();
+
+        let counts = index_scheduler.index_task_counts_by_kind("catto").unwrap();
+        assert_eq!(counts[&Kind::IndexCreation], 1);
+        assert_eq!(counts[&Kind::DocumentAdditionOrUpdate], 2);
+        assert_eq!(counts[&Kind::SettingsUpdate], 0);
+        let counts = index_scheduler.index_task_counts_by_kind("doggo").unwrap();
+        assert_eq!(counts[&Kind::IndexCreation], 1);
+        assert_eq!(counts[&Kind::DocumentAdditionOrUpdate], 0);
+    }
+
+    #[test]
+    fn wait_task_status_change() {
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+        index_scheduler.register(index_creation_task("catto", "mouse")).unwrap();
+
+        // nothing happens while the scheduler is stopped, we must wait until the timeout
+        let task = index_scheduler
+            .wait_task_status_change(0, Status::Enqueued, std::time::Duration::from_millis(100))
+            .unwrap();
+        assert_eq!(task.status, Status::Enqueued);
+
+        handle.advance_till([Start, BatchCreated]);
+
+        // the status already changed, we must return right away
+        let task = index_scheduler
+            .wait_task_status_change(0, Status::Enqueued, std::time::Duration::from_secs(3600))
+            .unwrap();
+        assert_eq!(task.status, Status::Processing);
+
+        std::thread::scope(|s| {
+            let waiter = s.spawn(|| {
+                index_scheduler.wait_task_status_change(
+                    0,
+                    Status::Processing,
+                    std::time::Duration::from_secs(60),
+                )
+            });
+            handle.finish_index_creation_batch();
+            let task = waiter.join().unwrap().unwrap();
+            assert_eq!(task.status, Status::Succeeded);
+        });
+
+        let err = index_scheduler
+            .wait_task_status_change(1, Status::Enqueued, std::time::Duration::ZERO)
+            .unwrap_err();
+        snapshot!(format!("{err}"), @"Task `1` not found.");
+    }
+
+    #[test]
+    fn indexes_matching() {
+        let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
+
+        for name in ["tenant_a_products", "tenant_a_users", "tenant_b_products"] {
+            index_scheduler.create_raw_index(name, None).unwrap();
+        }
+
+        let names = |pattern: &str| -> Vec<String> {
+            let pattern = IndexUidPattern::new_unchecked(pattern);
+            index_scheduler
+                .indexes_matching(&pattern)
+                .unwrap()
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect()
+        };
+
+        snapshot!(format!("{:?}", names("*")), @r###"["tenant_a_products", "tenant_a_users", "tenant_b_products"]"###);
+        snapshot!(format!("{:?}", names("tenant_a_*")), @r###"["tenant_a_products", "tenant_a_users"]"###);
+        snapshot!(format!("{:?}", names("tenant_b_products")), @r###"["tenant_b_products"]"###);
+        snapshot!(format!("{:?}", names("tenant_c_*")), @"[]");
+        snapshot!(format!("{:?}", names("tenant_c_products")), @"[]");
+    }
+
+    #[test]
+    fn index_path() {
+        let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
+
+        assert_eq!(index_scheduler.index_path("catto").unwrap(), None);
+
+        index_scheduler.create_raw_index("catto", None).unwrap();
+        let path = index_scheduler.index_path("catto").unwrap().unwrap();
+        assert!(path.join("data.mdb").exists());
+    }
+
+    #[test]
+    fn flush() {
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+        index_scheduler.register(index_creation_task("catto", "mouse")).unwrap();
+        index_scheduler.register(index_creation_task("doggo", "bone")).unwrap();
+
+        // nothing happens while the scheduler is stopped, we must wait until the timeout
+        let report = index_scheduler.flush(None, std::time::Duration::from_millis(100)).unwrap();
+        assert_eq!(report, FlushReport { pending: 2, ..FlushReport::default() });
+
+        handle.advance_till([Start, BatchCreated]);
+
+        std::thread::scope(|s| {
+            // the processing task must be waited for too
+            let waiter = s
+                .spawn(|| index_scheduler.flush(Some("catto"), std::time::Duration::from_secs(60)));
+            handle.finish_index_creation_batch();
+            let report = waiter.join().unwrap().unwrap();
+            assert_eq!(report, FlushReport { succeeded: 1, ..FlushReport::default() });
+        });
+
+        // the task that finished before the flush started is not reported
+        let report = index_scheduler.flush(None, std::time::Duration::ZERO).unwrap();
+        assert_eq!(report, FlushReport { pending: 1, ..FlushReport::default() });
+
+        handle.advance_one_successful_batch();
+
+        // there is nothing left to wait for
+        let report = index_scheduler.flush(None, std::time::Duration::from_secs(3600)).unwrap();
+        assert_eq!(report, FlushReport::default());
+    }
+
+    #[test]
+    fn register_many() {
+        let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
+
+        let tasks = index_scheduler
+            .register_many(vec![
+                index_creation_task("catto", "mouse"),
+                index_creation_task("doggo", "bone"),
+                index_creation_task("whalo", "plankton"),
+            ])
+            .unwrap();
+        let uids: Vec<_> = tasks.iter().map(|task| task.uid).collect();
+        assert_eq!(uids, vec![0, 1, 2]);
+
+        // a single invalid task prevents all the other tasks from being registered
+        let (_, file) = index_scheduler.create_update_file_with_uuid(0).unwrap();
+        file.persist().unwrap();
+        index_scheduler
+            .register_many(vec![
+                index_creation_task("birdo", "seed"),
+                replace_document_import_task("birdo", None, 0, 0),
+                KindWithContent::IndexSwap {
+                    swaps: vec![
+                        IndexSwap { indexes: ("catto".to_owned(), "doggo".to_owned()) },
+                        IndexSwap { indexes: ("catto".to_owned(), "whalo".to_owned()) },
+                    ],
+                },
+            ])
+            .unwrap_err();
+
+        let rtxn = index_scheduler.env.read_txn().unwrap();
+        assert_eq!(index_scheduler.all_tasks.len(&rtxn).unwrap(), 3);
+        drop(rtxn);
+        // the update file of the aborted document addition has been deleted
+        assert_eq!(index_scheduler.file_store.all_uuids().unwrap().count(), 0);
+
+        let task = index_scheduler.register(index_creation_task("birdo", "seed")).unwrap();
+        assert_eq!(task.uid, 3);
+    }
+
+    #[test]
+    fn register_settings_updates() {
+        let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
+
+        let mut settings = Settings::<Unchecked>::default();
+        settings.filterable_attributes = Setting::Set(BTreeSet::from([S("tenant")]));
+        let updates = ["doggo", "catto", "whalo"]
+            .into_iter()
+            .map(|index_uid| (S(index_uid), settings.clone()))
+            .collect();
+        let tasks = index_scheduler.register_settings_updates(updates, true).unwrap();
+
+        // the updates are enqueued in the alphabetical order of the indexes
+        let uids: BTreeMap<_, _> =
+            tasks.iter().map(|(index_uid, task)| (index_uid.as_str(), task.uid)).collect();
+        assert_eq!(uids, BTreeMap::from([("catto", 0), ("doggo", 1), ("whalo", 2)]));
+        for (index_uid, task) in &tasks {
+            assert_eq!(task.indexes(), [index_uid.as_str()]);
+            assert_eq!(task.kind.as_kind(), Kind::SettingsUpdate);
+        }
+    }
+
+    #[test]
+    fn max_indexes() {
+        let (index_scheduler, mut handle) =
+            IndexScheduler::test_with_custom_config(vec![], |options| {
+                options.max_indexes = Some(2);
+            });
+
+        index_scheduler.register(index_creation_task("catto", "mouse")).unwrap();
+        index_scheduler.register(index_creation_task("doggo", "bone")).unwrap();
+        index_scheduler.register(index_creation_task("whalo", "plankton")).unwrap();
+
+        // reaching exactly the limit is allowed
+        handle.advance_n_successful_batches(2);
+        handle.advance_one_failed_batch();
+
+        let rtxn = index_scheduler.env.read_txn().unwrap();
+        let task = index_scheduler.get_task(&rtxn, 2).unwrap().unwrap();
+        assert_eq!(task.status, Status::Failed);
+        assert_eq!(task.error, Some(Error::IndexLimitReached(2).into()));
+        assert!(!index_scheduler.index_mapper.index_exists(&rtxn, "whalo").unwrap());
+    }
+
+    /// Register a `DocumentAdditionOrUpdate` task on the `doggos` index for each content.
+    fn register_doggos_additions(index_scheduler: &IndexScheduler, contents: &[&str]) {
+        for (i, content) in contents.iter().enumerate() {
+            let (uuid, mut file) = index_scheduler.create_update_file_with_uuid(i as u128).unwrap();
+            let documents_count = read_json(content.as_bytes(), file.as_file_mut()).unwrap();
+            file.persist().unwrap();
+            index_scheduler
+                .register(KindWithContent::DocumentAdditionOrUpdate {
+                    index_uid: S("doggos"),
+                    primary_key: Some(S("id")),
+                    method: ReplaceDocuments,
+                    content_file: uuid,
+                    documents_count,
+                    allow_index_creation: true,
+                })
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn max_documents_per_index() {
+        let (index_scheduler, mut handle) =
+            IndexScheduler::test_with_custom_config(vec![], |options| {
+                options.max_documents_per_index = Some(2);
+            });
+
+        // reaching exactly the limit is allowed
+        register_doggos_additions(
+            &index_scheduler,
+            &[r#"[{ "id": 1, "doggo": "bob" }, { "id": 2, "doggo": "bill" }]"#],
+        );
+        handle.advance_one_successful_batch();
+
+        // the task fails but not the batch
+        register_doggos_additions(&index_scheduler, &[r#"{ "id": 3, "doggo": "bernard" }"#]);
+        handle.advance_one_successful_batch();
+
+        let rtxn = index_scheduler.env.read_txn().unwrap();
+        let task = index_scheduler.get_task(&rtxn, 1).unwrap().unwrap();
+        assert_eq!(task.status, Status::Failed);
+        assert_eq!(task.error, Some(Error::DocumentLimitReached { limit: 2, current: 3 }.into()));
+
+        let index = index_scheduler.index("doggos").unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.number_of_documents(&rtxn).unwrap(), 2);
+    }
+
+    #[test]
+    fn max_documents_per_index_is_checked_per_task() {
+        let (index_scheduler, mut handle) =
+            IndexScheduler::test_with_custom_config(vec![], |options| {
+                options.max_documents_per_index = Some(3);
+            });
+
+        register_doggos_additions(
+            &index_scheduler,
+            &[
+                r#"[{ "id": 1, "doggo": "bob" }, { "id": 2, "doggo": "bill" }]"#,
+                r#"[{ "id": 3, "doggo": "bernard" }, { "id": 4, "doggo": "bertrand" }]"#,
+                r#"{ "id": 3, "doggo": "bernard" }"#,
+            ],
+        );
+
+        // the three additions are autobatched together
+        handle.advance_one_successful_batch();
+
+        let rtxn = index_scheduler.env.read_txn().unwrap();
+        let statuses: Vec<_> = (0..3)
+            .map(|task_id| index_scheduler.get_task(&rtxn, task_id).unwrap().unwrap().status)
+            .collect();
+        assert_eq!(statuses, [Status::Succeeded, Status::Failed, Status::Succeeded]);
+        let task = index_scheduler.get_task(&rtxn, 1).unwrap().unwrap();
+        assert_eq!(task.error, Some(Error::DocumentLimitReached { limit: 3, current: 4 }.into()));
+
+        // only the documents of the tasks that fit in the limit are indexed
+        let index = index_scheduler.index("doggos").unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.number_of_documents(&rtxn).unwrap(), 3);
+    }
+
+    #[test]
+    fn max_documents_per_index_only_counts_new_documents() {
+        let (index_scheduler, mut handle) =
+            IndexScheduler::test_with_custom_config(vec![], |options| {
+                options.max_documents_per_index = Some(2);
+            });
+
+        register_doggos_additions(
+            &index_scheduler,
+            &[r#"[{ "id": 1, "doggo": "bob" }, { "id": 2, "doggo": "bill" }]"#],
+        );
+        handle.advance_one_successful_batch();
+
+        // the index is exactly at its limit but replacing a document doesn't change its size
+        register_doggos_additions(
+            &index_scheduler,
+            &[
+                r#"{ "id": 2, "doggo": "billy" }"#,
+                r#"[{ "id": 1, "doggo": "bobby" }, { "id": 3, "doggo": "bernard" }]"#,
+            ],
+        );
+        handle.advance_one_successful_batch();
+
+        let rtxn = index_scheduler.env.read_txn().unwrap();
+        let task = index_scheduler.get_task(&rtxn, 1).unwrap().unwrap();
+        assert_eq!(task.status, Status::Succeeded);
+        let task = index_scheduler.get_task(&rtxn, 2).unwrap().unwrap();
+        assert_eq!(task.status, Status::Failed);
+        assert_eq!(task.error, Some(Error::DocumentLimitReached { limit: 2, current: 3 }.into()));
+
+        let index = index_scheduler.index("doggos").unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.number_of_documents(&rtxn).unwrap(), 2);
+        let field_ids_map = index.fields_ids_map(&rtxn).unwrap();
+        let field_ids = field_ids_map.ids().collect::<Vec<_>>();
+        let doggos: Vec<_> = index
+            .all_documents(&rtxn)
+            .unwrap()
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|document| document["doggo"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(doggos, ["bob", "billy"]);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn tracing_spans() {
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        /// Records the name and the fields of every span that is created.
+        #[derive(Clone, Default)]
+        struct SpanCapture(Arc<Mutex<Vec<(&'static str, Vec<&'static str>)>>>);
+
+        impl Subscriber for SpanCapture {
+            fn enabled(&self, _: &Metadata) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes) -> Id {
+                let metadata = span.metadata();
+                let fields = metadata.fields().iter().map(|field| field.name()).collect();
+                let mut spans = self.0.lock().unwrap();
+                spans.push((metadata.name(), fields));
+                Id::from_u64(spans.len() as u64)
+            }
+
+            fn record(&self, _: &Id, _: &Record) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        // The batches are processed in another thread, the subscriber must be global.
+        let capture = SpanCapture::default();
+        tracing::subscriber::set_global_default(capture.clone()).unwrap();
+
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+        index_scheduler.register(index_creation_task("catto", "mouse")).unwrap();
+        handle.advance_one_successful_batch();
+
+        let spans = capture.0.lock().unwrap();
+        let fields_of = |name: &str| {
+            spans.iter().find(|(span, _)| *span == name).map(|(_, fields)| fields.clone())
+        };
+        assert_eq!(fields_of("update.enqueue").unwrap(), ["task", "index", "kind", "payload_size"]);
+        assert_eq!(fields_of("update.process").unwrap(), ["tasks", "duration_ms", "result"]);
+        assert_eq!(fields_of("index.create").unwrap(), ["index", "uuid"]);
+    }
+
+    #[test]
+    fn pause_and_resume_processing() {
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+        index_scheduler.pause_processing(Some("catto")).unwrap();
+        assert!(index_scheduler.is_processing_paused(Some("catto")).unwrap());
+        assert!(!index_scheduler.is_processing_paused(Some("doggo")).unwrap());
+        assert!(!index_scheduler.is_processing_paused(None).unwrap());
+        let paused = index_scheduler.paused_indexes().unwrap();
+        assert!(paused.is_paused(Some("catto")));
+        assert!(!paused.is_paused(Some("doggo")));
+
+        index_scheduler.register(index_creation_task("catto", "mouse")).unwrap();
+        index_scheduler.register(index_creation_task("doggo", "bone")).unwrap();
+        index_scheduler.register(KindWithContent::DocumentClear { index_uid: S("catto") }).unwrap();
+
+        let status_of = |task_id| {
+            let rtxn = index_scheduler.read_txn().unwrap();
+            index_scheduler.get_task(&rtxn, task_id).unwrap().unwrap().status
+        };
+        let has_next_batch = || {
+            let rtxn = index_scheduler.read_txn().unwrap();
+            index_scheduler.create_next_batch(&rtxn).unwrap().is_some()
+        };
+
+        // only the tasks of the index that isn't paused are processed
+        handle.advance_one_successful_batch();
+        assert_eq!(status_of(0), Status::Enqueued);
+        assert_eq!(status_of(1), Status::Succeeded);
+        assert_eq!(status_of(2), Status::Enqueued);
+        assert!(!has_next_batch());
+
+        // pausing every index takes precedence over the index being resumed
+        index_scheduler.pause_processing(None).unwrap();
+        index_scheduler.resume_processing(Some("catto")).unwrap();
+        assert!(index_scheduler.is_processing_paused(Some("catto")).unwrap());
+        assert!(!has_next_batch());
+
+        index_scheduler.resume_processing(None).unwrap();
+        assert!(!index_scheduler.is_processing_paused(Some("catto")).unwrap());
+        assert!(has_next_batch());
+
+        // the index creation and the document clear can't be autobatched together
+        handle.advance_n_successful_batches(2);
+        assert_eq!(status_of(0), Status::Succeeded);
+        assert_eq!(status_of(2), Status::Succeeded);
+    }
+
+    #[test]
+    fn pause_processing_skips_only_index_tasks() {
+        let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
+
+        let next_batch_ids = || {
+            let rtxn = index_scheduler.read_txn().unwrap();
+            index_scheduler.create_next_batch(&rtxn).unwrap().map(|batch| batch.ids())
+        };
+
+        index_scheduler.pause_processing(Some("catto")).unwrap();
+        index_scheduler
+            .register(KindWithContent::IndexSwap {
+                swaps: vec![IndexSwap { indexes: (S("catto"), S("doggo")) }],
+            })
+            .unwrap();
+        index_scheduler.register(KindWithContent::DocumentClear { index_uid: S("doggo") }).unwrap();
+        index_scheduler.register(index_creation_task("whalo", "plankton")).unwrap();
+
+        // the document clear must wait for the swap of the paused index
+        assert_eq!(next_batch_ids(), Some(vec![2]));
+
+        // dumps and snapshots are not associated with any index
+        index_scheduler.pause_processing(None).unwrap();
+        assert_eq!(next_batch_ids(), None);
+        index_scheduler.register(KindWithContent::SnapshotCreation).unwrap();
+        assert_eq!(next_batch_ids(), Some(vec![3]));
+    }
+
+    #[test]
+    fn document_addition_with_primary_key_keeps_settings_updated_at() {
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+        let (file, documents_count) = sample_documents(&index_scheduler, 0, 0);
+        file.persist().unwrap();
+        index_scheduler
+            .register(replace_document_import_task("doggo", Some("id"), 0, documents_count))
+            .unwrap();
+        handle.advance_one_successful_batch();
+
+        // the primary key is set through the settings builder without being a settings update
+        let index = index_scheduler.index("doggo").unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.primary_key(&rtxn).unwrap(), Some("id"));
+        assert_eq!(index.settings_updated_at(&rtxn).unwrap(), None);
+    }
 }
@@ -97,6 +97,16 @@ impl IndexScheduler {
         Ok(self.index_tasks.get(rtxn, index)?.unwrap_or_default())
     }
 
+    /// Returns the names of the paused indexes, [`crate::ALL_INDEXES_PAUSED`] included.
+    pub(crate) fn get_paused_indexes(&self, rtxn: &RoTxn) -> Result<HashSet<String>> {
+        let mut paused = HashSet::new();
+        for result in self.paused.iter(rtxn)? {
+            let (index, ()) = result?;
+            paused.insert(index.to_string());
+        }
+        Ok(paused)
+    }
+
     pub(crate) fn update_index(
         &self,
         wtxn: &mut RwTxn,
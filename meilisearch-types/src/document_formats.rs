@@ -10,6 +10,7 @@ use milli::Object;
 use serde::de::{SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer};
 use serde_json::error::Category;
+use serde_json::Value;
 
 use crate::error::{Code, ErrorCode};
 
@@ -36,6 +37,7 @@ impl fmt::Display for PayloadType {
 pub enum DocumentFormatError {
     Io(io::Error),
     MalformedPayload(Error, PayloadType),
+    MaxDepthExceeded(usize, PayloadType),
 }
 
 impl Display for DocumentFormatError {
@@ -74,6 +76,11 @@ impl Display for DocumentFormatError {
                 }
                 _ => write!(f, "The `{}` payload provided is malformed: `{}`.", b, me),
             },
+            Self::MaxDepthExceeded(max_depth, b) => write!(
+                f,
+                "The `{}` payload provided contains a document nested deeper than the maximum depth of {}.",
+                b, max_depth
+            ),
         }
     }
 }
@@ -100,15 +107,16 @@ impl ErrorCode for DocumentFormatError {
         match self {
             DocumentFormatError::Io(e) => e.error_code(),
             DocumentFormatError::MalformedPayload(_, _) => Code::MalformedPayload,
+            DocumentFormatError::MaxDepthExceeded(_, _) => Code::MalformedPayload,
         }
     }
 }
 
 /// Reads CSV from input and write an obkv batch to writer.
-pub fn read_csv(file: &File, writer: impl Write + Seek) -> Result<u64> {
+pub fn read_csv(file: &File, writer: impl Write + Seek, delimiter: u8) -> Result<u64> {
     let mut builder = DocumentsBatchBuilder::new(writer);
     let mmap = unsafe { MmapOptions::new().map(file)? };
-    let csv = csv::Reader::from_reader(mmap.as_ref());
+    let csv = csv::ReaderBuilder::new().delimiter(delimiter).from_reader(mmap.as_ref());
     builder.append_csv(csv).map_err(|e| (PayloadType::Csv, e))?;
 
     let count = builder.documents_count();
@@ -118,16 +126,22 @@ pub fn read_csv(file: &File, writer: impl Write + Seek) -> Result<u64> {
 }
 
 /// Reads JSON from temporary file  and write an obkv batch to writer.
-pub fn read_json(file: &File, writer: impl Write + Seek) -> Result<u64> {
+///
+/// When a `max_depth` is given, a document nested deeper is rejected, the document itself
+/// being at depth 1.
+pub fn read_json(file: &File, writer: impl Write + Seek, max_depth: Option<usize>) -> Result<u64> {
     let mut builder = DocumentsBatchBuilder::new(writer);
     let mmap = unsafe { MmapOptions::new().map(file)? };
     let mut deserializer = serde_json::Deserializer::from_slice(&mmap);
 
-    match array_each(&mut deserializer, |obj| builder.append_json_object(&obj)) {
+    match array_each(&mut deserializer, |obj: Object| {
+        check_depth(&obj, max_depth, PayloadType::Json)?;
+        builder.append_json_object(&obj).map_err(DocumentFormatError::Io)
+    }) {
         // The json data has been deserialized and does not need to be processed again.
         // The data has been transferred to the writer during the deserialization process.
         Ok(Ok(_)) => (),
-        Ok(Err(e)) => return Err(DocumentFormatError::Io(e)),
+        Ok(Err(e)) => return Err(e),
         Err(e) => {
             // Attempt to deserialize a single json string when the cause of the exception is not Category.data
             // Other types of deserialisation exceptions are returned directly to the front-end
@@ -141,6 +155,7 @@ pub fn read_json(file: &File, writer: impl Write + Seek) -> Result<u64> {
             let content: Object = serde_json::from_slice(&mmap)
                 .map_err(Error::Json)
                 .map_err(|e| (PayloadType::Json, e))?;
+            check_depth(&content, max_depth, PayloadType::Json)?;
             builder.append_json_object(&content).map_err(DocumentFormatError::Io)?;
         }
     }
@@ -152,12 +167,20 @@ pub fn read_json(file: &File, writer: impl Write + Seek) -> Result<u64> {
 }
 
 /// Reads JSON from temporary file  and write an obkv batch to writer.
-pub fn read_ndjson(file: &File, writer: impl Write + Seek) -> Result<u64> {
+///
+/// When a `max_depth` is given, a document nested deeper is rejected, the document itself
+/// being at depth 1.
+pub fn read_ndjson(
+    file: &File,
+    writer: impl Write + Seek,
+    max_depth: Option<usize>,
+) -> Result<u64> {
     let mut builder = DocumentsBatchBuilder::new(writer);
     let mmap = unsafe { MmapOptions::new().map(file)? };
 
     for result in serde_json::Deserializer::from_slice(&mmap).into_iter() {
         let object = result.map_err(Error::Json).map_err(|e| (PayloadType::Ndjson, e))?;
+        check_depth(&object, max_depth, PayloadType::Ndjson)?;
         builder.append_json_object(&object).map_err(Into::into).map_err(DocumentFormatError::Io)?;
     }
 
@@ -167,26 +190,43 @@ pub fn read_ndjson(file: &File, writer: impl Write + Seek) -> Result<u64> {
     Ok(count as u64)
 }
 
+fn check_depth(object: &Object, max_depth: Option<usize>, payload_type: PayloadType) -> Result<()> {
+    fn depth(value: &Value) -> usize {
+        match value {
+            Value::Object(object) => 1 + object.values().map(depth).max().unwrap_or(0),
+            Value::Array(array) => 1 + array.iter().map(depth).max().unwrap_or(0),
+            _ => 0,
+        }
+    }
+
+    match max_depth {
+        Some(max_depth) if 1 + object.values().map(depth).max().unwrap_or(0) > max_depth => {
+            Err(DocumentFormatError::MaxDepthExceeded(max_depth, payload_type))
+        }
+        _ => Ok(()),
+    }
+}
+
 /// The actual handling of the deserialization process in serde
 /// avoids storing the deserialized object in memory.
 ///
 /// ## References
 /// <https://serde.rs/stream-array.html>
 /// <https://github.com/serde-rs/json/issues/160>
-fn array_each<'de, D, T, F>(deserializer: D, f: F) -> std::result::Result<io::Result<u64>, D::Error>
+fn array_each<'de, D, T, F>(deserializer: D, f: F) -> std::result::Result<Result<u64>, D::Error>
 where
     D: Deserializer<'de>,
     T: Deserialize<'de>,
-    F: FnMut(T) -> io::Result<()>,
+    F: FnMut(T) -> Result<()>,
 {
     struct SeqVisitor<T, F>(F, PhantomData<T>);
 
     impl<'de, T, F> Visitor<'de> for SeqVisitor<T, F>
     where
         T: Deserialize<'de>,
-        F: FnMut(T) -> io::Result<()>,
+        F: FnMut(T) -> Result<()>,
     {
-        type Value = io::Result<u64>;
+        type Value = Result<u64>;
 
         fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
             formatter.write_str("a nonempty sequence")
@@ -195,7 +235,7 @@ where
         fn visit_seq<A>(
             mut self,
             mut seq: A,
-        ) -> std::result::Result<io::Result<u64>, <A as SeqAccess<'de>>::Error>
+        ) -> std::result::Result<Result<u64>, <A as SeqAccess<'de>>::Error>
         where
             A: SeqAccess<'de>,
         {
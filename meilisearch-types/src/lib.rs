@@ -5,6 +5,7 @@ pub mod error;
 pub mod index_uid;
 pub mod index_uid_pattern;
 pub mod keys;
+pub mod redacted;
 pub mod settings;
 pub mod star_or;
 pub mod tasks;
@@ -0,0 +1,172 @@
+use std::fmt::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// The maximum number of bytes the representation of a [`Redacted`] value can take.
+pub const MAX_REDACTED_LEN: usize = 1024;
+
+/// The number of characters after which a string of a [`Redacted`] value is truncated.
+pub const MAX_REDACTED_STRING_LEN: usize = 64;
+
+/// The number of entries above which an array or an object of a [`Redacted`] value
+/// is replaced by its length.
+pub const MAX_REDACTED_COLLECTION_LEN: usize = 10;
+
+static REDACTION_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Enables or disables the redaction of the [`Redacted`] values, e.g. to get the
+/// full representation of the logged values in tests.
+pub fn set_redaction(enabled: bool) {
+    REDACTION_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Wraps a value whose representation is meant to be logged.
+///
+/// The `Debug` representation is the JSON serialization of the value, in which the long strings
+/// are truncated and the large arrays and objects are replaced by their number of entries, e.g.
+/// `{"id":1,"tags":<1532 entries>}`. The `Display` representation is the one of the value.
+/// Both are cut after [`MAX_REDACTED_LEN`] bytes, which ensures a log line stays bounded
+/// regardless of the size of a payload.
+pub struct Redacted<'a, T: ?Sized>(&'a T);
+
+pub fn redacted<T: ?Sized>(value: &T) -> Redacted<'_, T> {
+    Redacted(value)
+}
+
+impl<T: Serialize + ?Sized> fmt::Debug for Redacted<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match serde_json::to_value(self.0) {
+            Ok(value) => value,
+            Err(e) => return write!(f, "<{e}>"),
+        };
+
+        if REDACTION_ENABLED.load(Ordering::Relaxed) {
+            write_bounded(f, |writer| write_summary(writer, &value))
+        } else {
+            write!(f, "{value}")
+        }
+    }
+}
+
+impl<T: fmt::Display + ?Sized> fmt::Display for Redacted<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if REDACTION_ENABLED.load(Ordering::Relaxed) {
+            write_bounded(f, |writer| write!(writer, "{}", self.0))
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+fn write_summary(writer: &mut impl Write, value: &Value) -> fmt::Result {
+    match value {
+        Value::String(s) => match s.char_indices().nth(MAX_REDACTED_STRING_LEN) {
+            Some((end, _)) => write!(writer, "{}", Value::String(format!("{}…", &s[..end]))),
+            None => write!(writer, "{value}"),
+        },
+        Value::Array(values) if values.len() > MAX_REDACTED_COLLECTION_LEN => {
+            write!(writer, "<{} entries>", values.len())
+        }
+        Value::Array(values) => {
+            writer.write_char('[')?;
+            for (i, value) in values.iter().enumerate() {
+                if i != 0 {
+                    writer.write_char(',')?;
+                }
+                write_summary(writer, value)?;
+            }
+            writer.write_char(']')
+        }
+        Value::Object(object) if object.len() > MAX_REDACTED_COLLECTION_LEN => {
+            write!(writer, "<{} entries>", object.len())
+        }
+        Value::Object(object) => {
+            writer.write_char('{')?;
+            for (i, (key, value)) in object.iter().enumerate() {
+                if i != 0 {
+                    writer.write_char(',')?;
+                }
+                write!(writer, "{}:", Value::String(key.clone()))?;
+                write_summary(writer, value)?;
+            }
+            writer.write_char('}')
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => write!(writer, "{value}"),
+    }
+}
+
+fn write_bounded(
+    f: &mut fmt::Formatter<'_>,
+    write: impl FnOnce(&mut BoundedWriter) -> fmt::Result,
+) -> fmt::Result {
+    let mut writer = BoundedWriter { inner: f, remaining: MAX_REDACTED_LEN, truncated: false };
+    match write(&mut writer) {
+        // The writer interrupts the formatting as soon as the limit is reached
+        // so that we do not format a large payload only to throw it away.
+        Err(_) if writer.truncated => f.write_str("…"),
+        result => result,
+    }
+}
+
+struct BoundedWriter<'a, 'b> {
+    inner: &'a mut fmt::Formatter<'b>,
+    remaining: usize,
+    truncated: bool,
+}
+
+impl fmt::Write for BoundedWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.len() <= self.remaining {
+            self.remaining -= s.len();
+            return self.inner.write_str(s);
+        }
+
+        let mut end = self.remaining;
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        self.inner.write_str(&s[..end])?;
+        self.remaining = 0;
+        self.truncated = true;
+        Err(fmt::Error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn redacted_summarizes_and_is_bounded() {
+        let document = json!({ "id": 1, "tags": ["a", "b"], "title": "doggo" });
+        assert_eq!(
+            format!("{:?}", redacted(&document)),
+            r#"{"id":1,"tags":["a","b"],"title":"doggo"}"#
+        );
+
+        let synonyms: Vec<_> = (0..1532).collect();
+        let document = json!({ "overview": "a".repeat(100), "synonyms": synonyms });
+        assert_eq!(
+            format!("{:?}", redacted(&document)),
+            format!(r#"{{"overview":"{}…","synonyms":<1532 entries>}}"#, "a".repeat(64))
+        );
+
+        let large: Vec<_> = (0..MAX_REDACTED_COLLECTION_LEN).map(|_| "é".repeat(1000)).collect();
+        let output = format!("{:?}", redacted(&large));
+        assert!(output.ends_with('…'));
+        assert!(output.len() <= MAX_REDACTED_LEN + '…'.len_utf8());
+
+        let output = format!("{}", redacted(&"a".repeat(10_000)));
+        assert_eq!(output, format!("{}…", "a".repeat(MAX_REDACTED_LEN)));
+
+        // the redaction can be disabled to get the full representation
+        set_redaction(false);
+        let output = format!("{:?}", redacted(&document));
+        set_redaction(true);
+        assert_eq!(output, document.to_string());
+    }
+}
@@ -239,6 +239,12 @@ impl Settings<Checked> {
 }
 
 impl Settings<Unchecked> {
+    /// Returns settings that only reset the searchable attributes, making all the fields of the
+    /// documents searchable again. It is the equivalent of `{ "searchableAttributes": null }`.
+    pub fn reset_searchable_to_all_fields() -> Settings<Unchecked> {
+        Settings { searchable_attributes: Setting::Reset, ..Default::default() }
+    }
+
     pub fn check(self) -> Settings<Checked> {
         let displayed_attributes = match self.displayed_attributes {
             Setting::Set(fields) => {
@@ -279,6 +285,96 @@ impl Settings<Unchecked> {
     }
 }
 
+impl<T> Settings<T> {
+    /// Returns a concise summary of the settings meant for the logs, e.g.
+    /// `Settings: displayed=<all>, searchable=[title, body], filterable=<unchanged>, ...`.
+    ///
+    /// A setting that is reset is written `<reset>` and a setting that is not set `<unchanged>`.
+    /// Collections larger than [`DESCRIBED_COLLECTION_MAX_LEN`] are only described by their number
+    /// of entries, e.g. `stopWords=<1532 entries>`, which keeps large settings out of the logs.
+    pub fn describe(&self) -> String {
+        let Self {
+            displayed_attributes,
+            searchable_attributes,
+            filterable_attributes,
+            sortable_attributes,
+            ranking_rules,
+            stop_words,
+            synonyms,
+            distinct_attribute,
+            typo_tolerance,
+            faceting,
+            pagination,
+            _kind,
+        } = self;
+
+        let fields = [
+            ("displayed", describe_setting(displayed_attributes, |a| describe_attributes(a))),
+            ("searchable", describe_setting(searchable_attributes, |a| describe_attributes(a))),
+            ("filterable", describe_setting(filterable_attributes, |l| describe_list(l))),
+            ("sortable", describe_setting(sortable_attributes, |l| describe_list(l))),
+            ("criteria", describe_setting(ranking_rules, |l| describe_list(l))),
+            ("stopWords", describe_setting(stop_words, |l| describe_list(l))),
+            (
+                "synonyms",
+                describe_setting(synonyms, |synonyms| {
+                    if synonyms.len() > DESCRIBED_COLLECTION_MAX_LEN {
+                        return describe_len(synonyms.len());
+                    }
+                    let synonyms = synonyms
+                        .iter()
+                        .map(|(word, synonyms)| format!("{word}: {}", describe_list(synonyms)))
+                        .collect::<Vec<_>>();
+                    format!("{{{}}}", synonyms.join(", "))
+                }),
+            ),
+            ("distinct", describe_setting(distinct_attribute, |attribute| attribute.clone())),
+            ("typoTolerance", describe_setting(typo_tolerance, |v| describe_json(v))),
+            ("faceting", describe_setting(faceting, |v| describe_json(v))),
+            ("pagination", describe_setting(pagination, |v| describe_json(v))),
+        ];
+
+        let fields: Vec<_> = fields.iter().map(|(name, value)| format!("{name}={value}")).collect();
+        format!("Settings: {}", fields.join(", "))
+    }
+}
+
+/// The number of elements above which [`Settings::describe`] only gives the length of a collection.
+pub const DESCRIBED_COLLECTION_MAX_LEN: usize = 10;
+
+fn describe_setting<V>(setting: &Setting<V>, describe: impl Fn(&V) -> String) -> String {
+    match setting {
+        Setting::Set(value) => describe(value),
+        Setting::Reset => String::from("<reset>"),
+        Setting::NotSet => String::from("<unchanged>"),
+    }
+}
+
+fn describe_attributes(attributes: &[String]) -> String {
+    if attributes.iter().any(|a| a == "*") {
+        String::from("<all>")
+    } else {
+        describe_list(attributes)
+    }
+}
+
+fn describe_list<'a, V: fmt::Display + 'a>(values: impl IntoIterator<Item = &'a V>) -> String {
+    let values: Vec<_> = values.into_iter().collect();
+    if values.len() > DESCRIBED_COLLECTION_MAX_LEN {
+        return describe_len(values.len());
+    }
+    let values: Vec<_> = values.iter().map(|v| v.to_string()).collect();
+    format!("[{}]", values.join(", "))
+}
+
+fn describe_len(len: usize) -> String {
+    format!("<{len} entries>")
+}
+
+fn describe_json(value: &impl Serialize) -> String {
+    serde_json::to_string(value).unwrap_or_else(|e| format!("<{e}>"))
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 #[serde(rename_all = "camelCase")]
@@ -652,4 +748,48 @@ pub(crate) mod test {
         assert_eq!(checked.displayed_attributes, Setting::Reset);
         assert_eq!(checked.searchable_attributes, Setting::Reset);
     }
+
+    #[test]
+    fn test_setting_describe() {
+        let settings = Settings {
+            displayed_attributes: Setting::Set(vec![String::from("*")]),
+            searchable_attributes: Setting::Set(vec![String::from("title"), String::from("body")]),
+            filterable_attributes: Setting::Reset,
+            sortable_attributes: Setting::NotSet,
+            ranking_rules: Setting::Set(vec![RankingRuleView::Words, RankingRuleView::Typo]),
+            stop_words: Setting::NotSet,
+            synonyms: Setting::Set(BTreeMap::from([(
+                String::from("tv"),
+                vec![String::from("television")],
+            )])),
+            distinct_attribute: Setting::Set(String::from("sku")),
+            typo_tolerance: Setting::NotSet,
+            faceting: Setting::Set(FacetingSettings { max_values_per_facet: Setting::Set(10) }),
+            pagination: Setting::Reset,
+            _kind: PhantomData::<Unchecked>,
+        };
+
+        assert_eq!(
+            settings.describe(),
+            "Settings: displayed=<all>, searchable=[title, body], filterable=<reset>, \
+             sortable=<unchanged>, criteria=[words, typo], stopWords=<unchanged>, \
+             synonyms={tv: [television]}, distinct=sku, typoTolerance=<unchanged>, \
+             faceting={\"maxValuesPerFacet\":10}, pagination=<reset>"
+        );
+    }
+
+    #[test]
+    fn test_setting_describe_summarizes_large_collections() {
+        let words: BTreeSet<_> = (0..1532).map(|i| format!("word{i}")).collect();
+        let synonyms: BTreeMap<_, _> = words.iter().map(|w| (w.clone(), vec![w.clone()])).collect();
+        let settings = Settings {
+            stop_words: Setting::Set(words),
+            synonyms: Setting::Set(synonyms),
+            ..Settings::<Unchecked>::default()
+        };
+
+        let description = settings.describe();
+        assert!(description.contains("stopWords=<1532 entries>"), "{description}");
+        assert!(description.contains("synonyms=<1532 entries>"), "{description}");
+    }
 }
@@ -64,20 +64,7 @@ impl Task {
 
     /// Return the content-uuid if there is one
     pub fn content_uuid(&self) -> Option<Uuid> {
-        match self.kind {
-            KindWithContent::DocumentAdditionOrUpdate { content_file, .. } => Some(content_file),
-            KindWithContent::DocumentDeletion { .. }
-            | KindWithContent::DocumentClear { .. }
-            | KindWithContent::SettingsUpdate { .. }
-            | KindWithContent::IndexDeletion { .. }
-            | KindWithContent::IndexCreation { .. }
-            | KindWithContent::IndexUpdate { .. }
-            | KindWithContent::IndexSwap { .. }
-            | KindWithContent::TaskCancelation { .. }
-            | KindWithContent::TaskDeletion { .. }
-            | KindWithContent::DumpCreation { .. }
-            | KindWithContent::SnapshotCreation => None,
-        }
+        self.kind.content_uuid()
     }
 }
 
@@ -158,6 +145,24 @@ impl KindWithContent {
         }
     }
 
+    /// Return the content-uuid if there is one
+    pub fn content_uuid(&self) -> Option<Uuid> {
+        match self {
+            KindWithContent::DocumentAdditionOrUpdate { content_file, .. } => Some(*content_file),
+            KindWithContent::DocumentDeletion { .. }
+            | KindWithContent::DocumentClear { .. }
+            | KindWithContent::SettingsUpdate { .. }
+            | KindWithContent::IndexDeletion { .. }
+            | KindWithContent::IndexCreation { .. }
+            | KindWithContent::IndexUpdate { .. }
+            | KindWithContent::IndexSwap { .. }
+            | KindWithContent::TaskCancelation { .. }
+            | KindWithContent::TaskDeletion { .. }
+            | KindWithContent::DumpCreation { .. }
+            | KindWithContent::SnapshotCreation => None,
+        }
+    }
+
     pub fn indexes(&self) -> Vec<&str> {
         use KindWithContent::*;
 
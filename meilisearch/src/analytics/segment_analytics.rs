@@ -231,6 +231,10 @@ struct Infos {
     http_addr: bool,
     http_payload_size_limit: Byte,
     log_level: String,
+    slow_batch_threshold_ms: Option<u64>,
+    max_indexes: Option<usize>,
+    max_documents_per_index: Option<u64>,
+    warm_up_budget_ms: Option<u64>,
     max_indexing_memory: MaxMemory,
     max_indexing_threads: MaxThreads,
     with_configuration_file: bool,
@@ -273,6 +277,10 @@ impl From<Opt> for Infos {
             ignore_dump_if_db_exists,
             dump_dir,
             log_level,
+            slow_batch_threshold_ms,
+            max_indexes,
+            max_documents_per_index,
+            warm_up_budget_ms,
             indexer_options,
             config_file_path,
             #[cfg(all(not(debug_assertions), feature = "analytics"))]
@@ -303,6 +311,10 @@ impl From<Opt> for Infos {
             http_addr: http_addr != default_http_addr(),
             http_payload_size_limit,
             log_level: log_level.to_string(),
+            slow_batch_threshold_ms,
+            max_indexes,
+            max_documents_per_index,
+            warm_up_budget_ms,
             max_indexing_memory,
             max_indexing_threads,
             with_configuration_file: config_file_path.is_some(),
@@ -24,6 +24,12 @@ pub enum MeilisearchHttpError {
     MissingPayload(PayloadType),
     #[error("The provided payload reached the size limit.")]
     PayloadTooLarge,
+    #[error("The CSV delimiter `{0}` is invalid. It must be a single ASCII character that is neither a double quote nor a line break.")]
+    InvalidCsvDelimiter(String),
+    #[error("The `csvDelimiter` parameter can only be used with a `text/csv` payload, not with a {0} payload.")]
+    CsvDelimiterWithWrongContentType(PayloadType),
+    #[error("The `jsonMaxDepth` parameter can only be used with an `application/json` or `application/x-ndjson` payload, not with a {0} payload.")]
+    JsonMaxDepthWithWrongContentType(PayloadType),
     #[error("Two indexes must be given for each swap. The list `[{}]` contains {} indexes.",
         .0.iter().map(|uid| format!("\"{uid}\"")).collect::<Vec<_>>().join(", "), .0.len()
     )]
@@ -57,6 +63,13 @@ impl ErrorCode for MeilisearchHttpError {
             MeilisearchHttpError::DocumentNotFound(_) => Code::DocumentNotFound,
             MeilisearchHttpError::InvalidExpression(_, _) => Code::InvalidSearchFilter,
             MeilisearchHttpError::PayloadTooLarge => Code::PayloadTooLarge,
+            MeilisearchHttpError::InvalidCsvDelimiter(_) => Code::InvalidDocumentCsvDelimiter,
+            MeilisearchHttpError::CsvDelimiterWithWrongContentType(_) => {
+                Code::InvalidDocumentCsvDelimiter
+            }
+            MeilisearchHttpError::JsonMaxDepthWithWrongContentType(_) => {
+                Code::InvalidDocumentJsonMaxDepth
+            }
             MeilisearchHttpError::SwapIndexPayloadWrongLength(_) => Code::InvalidSwapIndexes,
             MeilisearchHttpError::IndexUid(e) => e.error_code(),
             MeilisearchHttpError::SerdeJson(_) => Code::Internal,
@@ -18,7 +18,7 @@ use std::io::{BufReader, BufWriter};
 use std::path::Path;
 use std::sync::Arc;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use actix_cors::Cors;
 use actix_http::body::MessageBody;
@@ -36,6 +36,7 @@ use log::error;
 use meilisearch_auth::AuthController;
 use meilisearch_types::milli::documents::{DocumentsBatchBuilder, DocumentsBatchReader};
 use meilisearch_types::milli::update::{IndexDocumentsConfig, IndexDocumentsMethod};
+use meilisearch_types::milli::WarmUpTarget;
 use meilisearch_types::settings::apply_settings_to_builder;
 use meilisearch_types::tasks::KindWithContent;
 use meilisearch_types::versioning::{check_version_file, create_version_file};
@@ -184,9 +185,56 @@ pub fn setup_meilisearch(opt: &Opt) -> anyhow::Result<(Arc<IndexScheduler>, Auth
             .unwrap();
     }
 
+    // We warm up the indexes in a thread to not delay the startup
+    if let Some(warm_up_budget_ms) = opt.warm_up_budget_ms {
+        let deadline = Instant::now() + Duration::from_millis(warm_up_budget_ms);
+        let index_scheduler = index_scheduler.clone();
+        thread::Builder::new()
+            .name(String::from("warm-up-indexes"))
+            .spawn(move || {
+                if let Err(e) = warm_up_indexes(&index_scheduler, deadline) {
+                    error!("Error while warming up the indexes: {}", e);
+                }
+            })
+            .unwrap();
+    }
+
     Ok((index_scheduler, auth_controller))
 }
 
+/// Load the indexes in the page cache one after the other until the deadline is reached.
+///
+/// The indexes are only opened while the deadline is not reached.
+fn warm_up_indexes(
+    index_scheduler: &IndexScheduler,
+    deadline: Instant,
+) -> index_scheduler::Result<()> {
+    for name in index_scheduler.index_names()? {
+        if Instant::now() >= deadline {
+            log::info!("The warm-up budget is exhausted, the remaining indexes are not warmed up.");
+            break;
+        }
+
+        let report = match index_scheduler.warm_up(&name, &[WarmUpTarget::All], Some(deadline)) {
+            Ok(report) => report,
+            // the index has been deleted in the meantime
+            Err(index_scheduler::Error::IndexNotFound(_)) => continue,
+            Err(e) => return Err(e),
+        };
+        log::info!(
+            "Warmed up index `{}`: {} bytes read in {:.2?}.",
+            name,
+            report.bytes,
+            report.elapsed
+        );
+        if report.aborted {
+            log::info!("The warm-up budget is exhausted, the remaining indexes are not warmed up.");
+            break;
+        }
+    }
+    Ok(())
+}
+
 /// Try to start the IndexScheduler and AuthController without checking the VERSION file or anything.
 fn open_or_create_database_unchecked(
     opt: &Opt,
@@ -208,6 +256,9 @@ fn open_or_create_database_unchecked(
             index_size: opt.max_index_size.get_bytes() as usize,
             indexer_config: (&opt.indexer_options).try_into()?,
             autobatching_enabled: true,
+            slow_batch_threshold: opt.slow_batch_threshold_ms.map(Duration::from_millis),
+            max_indexes: opt.max_indexes,
+            max_documents_per_index: opt.max_documents_per_index,
         })?)
     };
 
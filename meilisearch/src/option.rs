@@ -47,6 +47,10 @@ const MEILI_IGNORE_MISSING_DUMP: &str = "MEILI_IGNORE_MISSING_DUMP";
 const MEILI_IGNORE_DUMP_IF_DB_EXISTS: &str = "MEILI_IGNORE_DUMP_IF_DB_EXISTS";
 const MEILI_DUMP_DIR: &str = "MEILI_DUMP_DIR";
 const MEILI_LOG_LEVEL: &str = "MEILI_LOG_LEVEL";
+const MEILI_SLOW_BATCH_THRESHOLD_MS: &str = "MEILI_SLOW_BATCH_THRESHOLD_MS";
+const MEILI_MAX_INDEXES: &str = "MEILI_MAX_INDEXES";
+const MEILI_MAX_DOCUMENTS_PER_INDEX: &str = "MEILI_MAX_DOCUMENTS_PER_INDEX";
+const MEILI_WARM_UP_BUDGET_MS: &str = "MEILI_WARM_UP_BUDGET_MS";
 #[cfg(feature = "metrics")]
 const MEILI_ENABLE_METRICS_ROUTE: &str = "MEILI_ENABLE_METRICS_ROUTE";
 
@@ -287,6 +291,29 @@ pub struct Opt {
     #[serde(default)]
     pub log_level: LogLevel,
 
+    /// Logs a warning for every batch of tasks that takes longer than the given
+    /// number of milliseconds to process. Slow batches are not logged by default.
+    #[clap(long, env = MEILI_SLOW_BATCH_THRESHOLD_MS)]
+    pub slow_batch_threshold_ms: Option<u64>,
+
+    /// Sets the maximum number of indexes of the instance. Creating an index
+    /// beyond this limit fails. The number of indexes is unlimited by default.
+    #[clap(long, env = MEILI_MAX_INDEXES)]
+    pub max_indexes: Option<usize>,
+
+    /// Sets the maximum number of documents each index can contain. A document
+    /// addition whose new documents would exceed this limit fails without indexing
+    /// anything, replacing existing documents is always allowed. The number of
+    /// documents is unlimited by default.
+    #[clap(long, env = MEILI_MAX_DOCUMENTS_PER_INDEX)]
+    pub max_documents_per_index: Option<u64>,
+
+    /// Loads the indexes in the page cache in the background after startup, so that the first
+    /// searches are not slowed down by reading the disk. The warm-up stops after the given number
+    /// of milliseconds. Indexes are not warmed up by default.
+    #[clap(long, env = MEILI_WARM_UP_BUDGET_MS)]
+    pub warm_up_budget_ms: Option<u64>,
+
     /// Enables Prometheus metrics and /metrics route.
     #[cfg(feature = "metrics")]
     #[clap(long, env = MEILI_ENABLE_METRICS_ROUTE)]
@@ -374,6 +401,10 @@ impl Opt {
             schedule_snapshot,
             dump_dir,
             log_level,
+            slow_batch_threshold_ms,
+            max_indexes,
+            max_documents_per_index,
+            warm_up_budget_ms,
             indexer_options,
             import_snapshot: _,
             ignore_missing_snapshot: _,
@@ -423,6 +454,24 @@ impl Opt {
 
         export_to_env_if_not_present(MEILI_DUMP_DIR, dump_dir);
         export_to_env_if_not_present(MEILI_LOG_LEVEL, log_level.to_string());
+        if let Some(slow_batch_threshold_ms) = slow_batch_threshold_ms {
+            export_to_env_if_not_present(
+                MEILI_SLOW_BATCH_THRESHOLD_MS,
+                slow_batch_threshold_ms.to_string(),
+            );
+        }
+        if let Some(max_indexes) = max_indexes {
+            export_to_env_if_not_present(MEILI_MAX_INDEXES, max_indexes.to_string());
+        }
+        if let Some(max_documents_per_index) = max_documents_per_index {
+            export_to_env_if_not_present(
+                MEILI_MAX_DOCUMENTS_PER_INDEX,
+                max_documents_per_index.to_string(),
+            );
+        }
+        if let Some(warm_up_budget_ms) = warm_up_budget_ms {
+            export_to_env_if_not_present(MEILI_WARM_UP_BUDGET_MS, warm_up_budget_ms.to_string());
+        }
         #[cfg(feature = "metrics")]
         {
             export_to_env_if_not_present(
@@ -17,6 +17,7 @@ use meilisearch_types::error::ResponseError;
 use meilisearch_types::heed::RoTxn;
 use meilisearch_types::index_uid::IndexUid;
 use meilisearch_types::milli::update::IndexDocumentsMethod;
+use meilisearch_types::redacted::redacted;
 use meilisearch_types::star_or::OptionStarOrList;
 use meilisearch_types::tasks::KindWithContent;
 use meilisearch_types::{milli, Document, Index};
@@ -100,7 +101,7 @@ pub async fn get_document(
 
     let index = index_scheduler.index(&index_uid)?;
     let document = retrieve_document(&index, &document_id, attributes_to_retrieve)?;
-    debug!("returns: {:?}", document);
+    debug!("returns: {:?}", redacted(&document));
     Ok(HttpResponse::Ok().json(document))
 }
 
@@ -151,15 +152,19 @@ pub async fn get_all_documents(
 
     let ret = PaginationView::new(offset.0, limit.0, total as usize, documents);
 
-    debug!("returns: {:?}", ret);
+    debug!("returns: {:?}", redacted(&ret));
     Ok(HttpResponse::Ok().json(ret))
 }
 
-#[derive(Deserialize, Debug, Deserr)]
+#[derive(Debug, Deserr)]
 #[deserr(error = DeserrJsonError, rename_all = camelCase, deny_unknown_fields)]
 pub struct UpdateDocumentsQuery {
     #[deserr(default, error = DeserrJsonError<InvalidIndexPrimaryKey>)]
     pub primary_key: Option<String>,
+    #[deserr(default, error = DeserrJsonError<InvalidDocumentCsvDelimiter>)]
+    pub csv_delimiter: Option<String>,
+    #[deserr(default, error = DeserrJsonError<InvalidDocumentJsonMaxDepth>)]
+    pub json_max_depth: Option<Param<usize>>,
 }
 
 pub async fn add_documents(
@@ -182,7 +187,7 @@ pub async fn add_documents(
         extract_mime_type(&req)?,
         index_scheduler,
         index_uid,
-        params.primary_key,
+        params,
         body,
         IndexDocumentsMethod::ReplaceDocuments,
         allow_index_creation,
@@ -203,6 +208,7 @@ pub async fn update_documents(
     let index_uid = IndexUid::try_from(index_uid.into_inner())?;
 
     debug!("called with params: {:?}", params);
+    let params = params.into_inner();
 
     analytics.update_documents(&params, index_scheduler.index(&index_uid).is_err(), &req);
 
@@ -211,7 +217,7 @@ pub async fn update_documents(
         extract_mime_type(&req)?,
         index_scheduler,
         index_uid,
-        params.into_inner().primary_key,
+        params,
         body,
         IndexDocumentsMethod::UpdateDocuments,
         allow_index_creation,
@@ -225,11 +231,14 @@ async fn document_addition(
     mime_type: Option<Mime>,
     index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_ADD }>, Data<IndexScheduler>>,
     index_uid: IndexUid,
-    primary_key: Option<String>,
+    params: UpdateDocumentsQuery,
     mut body: Payload,
     method: IndexDocumentsMethod,
     allow_index_creation: bool,
 ) -> Result<SummarizedTaskView, MeilisearchHttpError> {
+    let UpdateDocumentsQuery { primary_key, csv_delimiter, json_max_depth } = params;
+    let json_max_depth = json_max_depth.as_deref().copied();
+
     let format = match mime_type.as_ref().map(|m| (m.type_().as_str(), m.subtype().as_str())) {
         Some(("application", "json")) => PayloadType::Json,
         Some(("application", "x-ndjson")) => PayloadType::Ndjson,
@@ -245,6 +254,21 @@ async fn document_addition(
         }
     };
 
+    let csv_delimiter = match csv_delimiter {
+        None => b',',
+        Some(_) if !matches!(format, PayloadType::Csv) => {
+            return Err(MeilisearchHttpError::CsvDelimiterWithWrongContentType(format))
+        }
+        Some(delimiter) => match delimiter.as_bytes() {
+            [byte] if !matches!(byte, b'"' | b'\n' | b'\r') => *byte,
+            _ => return Err(MeilisearchHttpError::InvalidCsvDelimiter(delimiter)),
+        },
+    };
+
+    if json_max_depth.is_some() && matches!(format, PayloadType::Csv) {
+        return Err(MeilisearchHttpError::JsonMaxDepthWithWrongContentType(format));
+    }
+
     let (uuid, mut update_file) = index_scheduler.create_update_file()?;
 
     let temp_file = match tempfile() {
@@ -284,9 +308,11 @@ async fn document_addition(
     let read_file = buffer.into_inner().into_std().await;
     let documents_count = tokio::task::spawn_blocking(move || {
         let documents_count = match format {
-            PayloadType::Json => read_json(&read_file, update_file.as_file_mut())?,
-            PayloadType::Csv => read_csv(&read_file, update_file.as_file_mut())?,
-            PayloadType::Ndjson => read_ndjson(&read_file, update_file.as_file_mut())?,
+            PayloadType::Json => read_json(&read_file, update_file.as_file_mut(), json_max_depth)?,
+            PayloadType::Csv => read_csv(&read_file, update_file.as_file_mut(), csv_delimiter)?,
+            PayloadType::Ndjson => {
+                read_ndjson(&read_file, update_file.as_file_mut(), json_max_depth)?
+            }
         };
         // we NEED to persist the file here because we moved the `udpate_file` in another task.
         update_file.persist()?;
@@ -343,7 +369,7 @@ pub async fn delete_documents(
     req: HttpRequest,
     analytics: web::Data<dyn Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
-    debug!("called with params: {:?}", body);
+    debug!("called with params: {:?}", redacted(&*body));
     let index_uid = IndexUid::try_from(index_uid.into_inner())?;
 
     analytics.delete_documents(DocumentDeletionKind::PerBatch, &req);
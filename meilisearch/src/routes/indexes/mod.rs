@@ -12,6 +12,7 @@ use meilisearch_types::error::deserr_codes::*;
 use meilisearch_types::error::{Code, ResponseError};
 use meilisearch_types::index_uid::IndexUid;
 use meilisearch_types::milli::{self, FieldDistribution, Index};
+use meilisearch_types::redacted::redacted;
 use meilisearch_types::tasks::KindWithContent;
 use serde::Serialize;
 use serde_json::json;
@@ -56,6 +57,8 @@ pub struct IndexView {
     pub created_at: OffsetDateTime,
     #[serde(with = "time::serde::rfc3339")]
     pub updated_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub settings_updated_at: Option<OffsetDateTime>,
     pub primary_key: Option<String>,
 }
 
@@ -66,6 +69,7 @@ impl IndexView {
             uid,
             created_at: index.created_at(&rtxn)?,
             updated_at: index.updated_at(&rtxn)?,
+            settings_updated_at: index.settings_updated_at(&rtxn)?,
             primary_key: index.primary_key(&rtxn)?.map(String::from),
         })
     }
@@ -99,7 +103,7 @@ pub async fn list_indexes(
 
     let ret = paginate.as_pagination().auto_paginate_sized(indexes.into_iter());
 
-    debug!("returns: {:?}", ret);
+    debug!("returns: {:?}", redacted(&ret));
     Ok(HttpResponse::Ok().json(ret))
 }
 
@@ -236,6 +240,7 @@ pub async fn get_index_stats(
 pub struct IndexStats {
     pub number_of_documents: u64,
     pub is_indexing: bool,
+    pub is_paused: bool,
     pub field_distribution: FieldDistribution,
 }
 
@@ -246,11 +251,13 @@ impl IndexStats {
     ) -> Result<Self, ResponseError> {
         // we check if there is currently a task processing associated with this index.
         let is_processing = index_scheduler.is_index_processing(&index_uid)?;
+        let paused_indexes = index_scheduler.paused_indexes()?;
         let index = index_scheduler.index(&index_uid)?;
         let rtxn = index.read_txn()?;
         Ok(IndexStats {
             number_of_documents: index.number_of_documents(&rtxn)?,
             is_indexing: is_processing,
+            is_paused: paused_indexes.is_paused(Some(&index_uid)),
             field_distribution: index.field_distribution(&rtxn)?,
         })
     }
@@ -227,7 +227,12 @@ pub struct Stats {
     pub database_size: u64,
     #[serde(serialize_with = "time::serde::rfc3339::option::serialize")]
     pub last_update: Option<OffsetDateTime>,
+    pub is_paused: bool,
     pub indexes: BTreeMap<String, IndexStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_indexes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_documents_per_index: Option<u64>,
 }
 
 async fn get_stats(
@@ -260,6 +265,7 @@ pub fn create_all_stats(
     )?;
     // accumulate the size of each indexes
     let processing_index = processing_task.first().and_then(|task| task.index_uid());
+    let paused_indexes = index_scheduler.paused_indexes()?;
     for (name, index) in index_scheduler.indexes()? {
         if !search_rules.is_index_authorized(&name) {
             continue;
@@ -271,6 +277,7 @@ pub fn create_all_stats(
         let stats = IndexStats {
             number_of_documents: index.number_of_documents(&rtxn)?,
             is_indexing: processing_index.map_or(false, |index_name| name == index_name),
+            is_paused: paused_indexes.is_paused(Some(&name)),
             field_distribution: index.field_distribution(&rtxn)?,
         };
 
@@ -284,7 +291,14 @@ pub fn create_all_stats(
     database_size += auth_controller.size()?;
     database_size += index_scheduler.compute_update_file_size()?;
 
-    let stats = Stats { database_size, last_update: last_task, indexes };
+    let stats = Stats {
+        database_size,
+        last_update: last_task,
+        is_paused: paused_indexes.is_paused(None),
+        indexes,
+        max_indexes: index_scheduler.max_indexes(),
+        max_documents_per_index: index_scheduler.max_documents_per_index(),
+    };
     Ok(stats)
 }
 
@@ -376,6 +376,191 @@ async fn error_add_malformed_csv_documents() {
     "###);
 }
 
+#[actix_rt::test]
+async fn add_csv_documents_with_custom_delimiter() {
+    let document = "id;content\n1;hello, world\n2;hello world";
+
+    let server = Server::new().await;
+    let app = server.init_web_app().await;
+
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents?csvDelimiter=;")
+        .set_payload(document.to_string())
+        .insert_header(("content-type", "text/csv"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    snapshot!(res.status(), @"202 Accepted");
+
+    let index = server.index("dog");
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "succeeded");
+
+    let (response, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    assert_eq!(code, 200);
+    assert_eq!(
+        response["results"],
+        json!([
+            { "id": "1", "content": "hello, world" },
+            { "id": "2", "content": "hello world" },
+        ])
+    );
+}
+
+#[actix_rt::test]
+async fn error_add_documents_invalid_csv_delimiter() {
+    let server = Server::new().await;
+    let app = server.init_web_app().await;
+
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents?csvDelimiter=%22")
+        .set_payload("id\n1".to_string())
+        .insert_header(("content-type", "text/csv"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let body = test::read_body(res).await;
+    let response: Value = serde_json::from_slice(&body).unwrap_or_default();
+    snapshot!(status_code, @"400 Bad Request");
+    snapshot!(json_string!(response),
+        @r###"
+    {
+      "message": "The CSV delimiter `\"` is invalid. It must be a single ASCII character that is neither a double quote nor a line break.",
+      "code": "invalid_document_csv_delimiter",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_document_csv_delimiter"
+    }
+    "###);
+
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents?csvDelimiter=;")
+        .set_payload(json!([{ "id": 1 }]).to_string())
+        .insert_header(("content-type", "application/json"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let body = test::read_body(res).await;
+    let response: Value = serde_json::from_slice(&body).unwrap_or_default();
+    snapshot!(status_code, @"400 Bad Request");
+    snapshot!(json_string!(response),
+        @r###"
+    {
+      "message": "The `csvDelimiter` parameter can only be used with a `text/csv` payload, not with a json payload.",
+      "code": "invalid_document_csv_delimiter",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_document_csv_delimiter"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn add_documents_with_json_max_depth() {
+    let server = Server::new().await;
+    let app = server.init_web_app().await;
+
+    let document = json!([{ "id": 1, "a": { "b": { "c": 1 } } }]);
+
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents?jsonMaxDepth=2")
+        .set_payload(document.to_string())
+        .insert_header(("content-type", "application/json"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let body = test::read_body(res).await;
+    let response: Value = serde_json::from_slice(&body).unwrap_or_default();
+    snapshot!(status_code, @"400 Bad Request");
+    snapshot!(json_string!(response),
+        @r###"
+    {
+      "message": "The `json` payload provided contains a document nested deeper than the maximum depth of 2.",
+      "code": "malformed_payload",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#malformed_payload"
+    }
+    "###);
+
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents?jsonMaxDepth=2")
+        .set_payload(r#"{ "id": 1, "a": [[1]] }"#)
+        .insert_header(("content-type", "application/x-ndjson"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let body = test::read_body(res).await;
+    let response: Value = serde_json::from_slice(&body).unwrap_or_default();
+    snapshot!(status_code, @"400 Bad Request");
+    snapshot!(json_string!(response),
+        @r###"
+    {
+      "message": "The `ndjson` payload provided contains a document nested deeper than the maximum depth of 2.",
+      "code": "malformed_payload",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#malformed_payload"
+    }
+    "###);
+
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents?jsonMaxDepth=3")
+        .set_payload(document.to_string())
+        .insert_header(("content-type", "application/json"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    snapshot!(status_code, @"202 Accepted");
+
+    let index = server.index("dog");
+    index.wait_task(0).await;
+    let (response, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    assert_eq!(code, 200);
+    assert_eq!(response["results"], document);
+}
+
+#[actix_rt::test]
+async fn error_add_documents_invalid_json_max_depth() {
+    let server = Server::new().await;
+    let app = server.init_web_app().await;
+
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents?jsonMaxDepth=deep")
+        .set_payload(json!([{ "id": 1 }]).to_string())
+        .insert_header(("content-type", "application/json"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let body = test::read_body(res).await;
+    let response: Value = serde_json::from_slice(&body).unwrap_or_default();
+    snapshot!(status_code, @"400 Bad Request");
+    snapshot!(json_string!(response),
+        @r###"
+    {
+      "message": "Invalid value at `.jsonMaxDepth`: could not parse `deep` as a positive integer",
+      "code": "invalid_document_json_max_depth",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_document_json_max_depth"
+    }
+    "###);
+
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents?jsonMaxDepth=2")
+        .set_payload("id\n1".to_string())
+        .insert_header(("content-type", "text/csv"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let body = test::read_body(res).await;
+    let response: Value = serde_json::from_slice(&body).unwrap_or_default();
+    snapshot!(status_code, @"400 Bad Request");
+    snapshot!(json_string!(response),
+        @r###"
+    {
+      "message": "The `jsonMaxDepth` parameter can only be used with an `application/json` or `application/x-ndjson` payload, not with a csv payload.",
+      "code": "invalid_document_json_max_depth",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_document_json_max_depth"
+    }
+    "###);
+}
+
 #[actix_rt::test]
 async fn error_add_malformed_json_documents() {
     let document = r#"[{"id": 1}, {id: 2}]"#;
@@ -798,6 +983,7 @@ async fn document_addition_with_primary_key() {
       "uid": "test",
       "createdAt": "[date]",
       "updatedAt": "[date]",
+      "settingsUpdatedAt": null,
       "primaryKey": "primary"
     }
     "###);
@@ -33,8 +33,10 @@ async fn update_primary_key() {
         OffsetDateTime::parse(response["updatedAt"].as_str().unwrap(), &Rfc3339).unwrap();
     assert!(created_at < updated_at);
 
+    // updating the primary key is not a settings update
+    assert!(response["settingsUpdatedAt"].is_null());
     assert_eq!(response["primaryKey"], "primary");
-    assert_eq!(response.as_object().unwrap().len(), 4);
+    assert_eq!(response.as_object().unwrap().len(), 5);
 }
 
 #[actix_rt::test]
@@ -352,6 +352,36 @@ async fn search_non_filterable_facets() {
     "###);
 }
 
+#[actix_rt::test]
+async fn search_non_faceted_distinct() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.update_settings(json!({"filterableAttributes": ["title"]})).await;
+    index.wait_task(0).await;
+
+    let (response, code) = index.search_post(json!({"distinct": "doggo"})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Attribute `doggo` cannot be used as distinct. Available attributes are: `title`.",
+      "code": "invalid_search_distinct",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_distinct"
+    }
+    "###);
+
+    let (response, code) = index.search_get("distinct=doggo").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Attribute `doggo` cannot be used as distinct. Available attributes are: `title`.",
+      "code": "invalid_search_distinct",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_distinct"
+    }
+    "###);
+}
+
 #[actix_rt::test]
 async fn search_non_filterable_facets_multiple_filterable() {
     let server = Server::new().await;
@@ -535,6 +565,34 @@ async fn search_bad_matching_strategy() {
     "###);
 }
 
+#[actix_rt::test]
+async fn search_bad_prefix_search() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.search_post(json!({"prefixSearch": "doggo"})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Unknown value `doggo` at `.prefixSearch`: expected one of `last`, `none`",
+      "code": "invalid_search_prefix_search",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_prefix_search"
+    }
+    "###);
+
+    let (response, code) = index.search_get("prefixSearch=doggo").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Unknown value `doggo` for parameter `prefixSearch`: expected one of `last`, `none`",
+      "code": "invalid_search_prefix_search",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_prefix_search"
+    }
+    "###);
+}
+
 #[actix_rt::test]
 async fn filter_invalid_syntax_object() {
     let server = Server::new().await;
@@ -38,9 +38,11 @@ async fn stats() {
     assert_eq!(code, 200);
     assert!(response.get("databaseSize").is_some());
     assert!(response.get("lastUpdate").is_some());
+    assert!(response["isPaused"] == false);
     assert!(response["indexes"].get("test").is_some());
     assert_eq!(response["indexes"]["test"]["numberOfDocuments"], 0);
     assert!(response["indexes"]["test"]["isIndexing"] == false);
+    assert!(response["indexes"]["test"]["isPaused"] == false);
 
     let documents = json!([
         {
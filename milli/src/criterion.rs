@@ -55,6 +55,40 @@ impl Criterion {
             _otherwise => None,
         }
     }
+
+    /// Returns a human-readable explanation of how this criterion ranks the documents.
+    pub fn description(&self) -> String {
+        match self {
+            Criterion::Words => {
+                "Ranks first the documents containing the most query words.".to_string()
+            }
+            Criterion::Typo => {
+                "Ranks first the documents matching the query with the fewest typos.".to_string()
+            }
+            Criterion::Proximity => {
+                "Ranks first the documents where the query words are closest to each other."
+                    .to_string()
+            }
+            Criterion::Attribute => {
+                "Ranks first the documents matching the query in their most important attributes."
+                    .to_string()
+            }
+            Criterion::Sort => {
+                "Ranks the documents according to the sort parameter given at search time."
+                    .to_string()
+            }
+            Criterion::Exactness => {
+                "Ranks first the documents whose matched words are the most similar to the query words."
+                    .to_string()
+            }
+            Criterion::Asc(field) => {
+                format!("Ranks first the documents with the lowest `{field}` value.")
+            }
+            Criterion::Desc(field) => {
+                format!("Ranks first the documents with the highest `{field}` value.")
+            }
+        }
+    }
 }
 
 impl FromStr for Criterion {
@@ -69,6 +103,11 @@ impl FromStr for Criterion {
             "sort" => Ok(Criterion::Sort),
             "exactness" => Ok(Criterion::Exactness),
             text => match AscDesc::from_str(text)? {
+                AscDesc::Asc(Member::Field(field)) | AscDesc::Desc(Member::Field(field))
+                    if field.is_empty() =>
+                {
+                    Err(CriterionError::InvalidName { name: text.to_string() })
+                }
                 AscDesc::Asc(Member::Field(field)) => Ok(Criterion::Asc(field)),
                 AscDesc::Desc(Member::Field(field)) => Ok(Criterion::Desc(field)),
                 AscDesc::Asc(Member::Geo(_)) | AscDesc::Desc(Member::Geo(_)) => {
@@ -149,6 +188,9 @@ mod tests {
             ("proximity attribute", InvalidName { name: S("proximity attribute") }),
             ("price", InvalidName { name: S("price") }),
             ("asc:price", InvalidName { name: S("asc:price") }),
+            (":asc", InvalidName { name: S(":asc") }),
+            (":desc", InvalidName { name: S(":desc") }),
+            ("asc(price)", InvalidName { name: S("asc(price)") }),
             ("price:deesc", InvalidName { name: S("price:deesc") }),
             ("price:aasc", InvalidName { name: S("price:aasc") }),
             ("price:asc and desc", InvalidName { name: S("price:asc and desc") }),
@@ -185,4 +227,16 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn criterion_description() {
+        assert_eq!(
+            Criterion::Typo.description(),
+            "Ranks first the documents matching the query with the fewest typos."
+        );
+        assert_eq!(
+            Criterion::Desc(S("release_date")).description(),
+            "Ranks first the documents with the highest `release_date` value."
+        );
+    }
 }
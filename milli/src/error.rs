@@ -95,6 +95,16 @@ pub enum UserError {
     CriterionError(#[from] CriterionError),
     #[error("Maximum number of documents reached.")]
     DocumentLimitReached,
+    #[error("Attribute `{}` cannot be used as distinct. {}",
+        .field,
+        match .valid_fields.is_empty() {
+            true => "This index does not have configured filterable or sortable attributes.".to_string(),
+            false => format!("Available attributes are: `{}`.",
+                    valid_fields.iter().map(AsRef::as_ref).collect::<Vec<&str>>().join(", ")
+                ),
+        }
+    )]
+    InvalidDistinctAttribute { field: String, valid_fields: BTreeSet<String> },
     #[error(
         "Document identifier `{}` is invalid. \
 A document identifier can be of type integer or string, \
@@ -106,6 +116,18 @@ only composed of alphanumeric characters (a-z A-Z 0-9), hyphens (-) and undersco
         invalid_facets_name: BTreeSet<String>,
         valid_facets_name: BTreeSet<String>,
     },
+    #[error("Attribute `{}` is not filterable. {}",
+        .field,
+        match .valid_fields.is_empty() {
+            true => "A facet histogram can only be computed on filterable attributes and this index does not have configured filterable attributes.".to_string(),
+            false => format!("A facet histogram can only be computed on filterable attributes. Available filterable attributes are: `{}`.",
+                    valid_fields.iter().map(AsRef::as_ref).collect::<Vec<&str>>().join(", ")
+                ),
+        }
+    )]
+    InvalidFacetHistogramAttribute { field: String, valid_fields: BTreeSet<String> },
+    #[error("Attribute `{field}` has no numeric values. A facet histogram can only be computed on numeric values.")]
+    NonNumericFacetHistogram { field: String },
     #[error(transparent)]
     InvalidGeoField(#[from] GeoError),
     #[error("{0}")]
@@ -3,10 +3,13 @@ use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::mem::size_of;
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 use heed::flags::Flags;
 use heed::types::*;
 use heed::{CompactionOption, Database, PolyDatabase, RoTxn, RwTxn};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use roaring::RoaringBitmap;
 use rstar::RTree;
 use time::OffsetDateTime;
@@ -22,8 +25,8 @@ use crate::heed_codec::StrRefCodec;
 use crate::{
     default_criteria, BEU32StrCodec, BoRoaringBitmapCodec, CboRoaringBitmapCodec, Criterion,
     DocumentId, ExternalDocumentsIds, FacetDistribution, FieldDistribution, FieldId,
-    FieldIdWordCountCodec, GeoPoint, ObkvCodec, Result, RoaringBitmapCodec, RoaringBitmapLenCodec,
-    Search, StrBEU32Codec, U8StrStrCodec, BEU16, BEU32,
+    FieldIdWordCountCodec, GeoPoint, Object, ObkvCodec, Result, RoaringBitmapCodec,
+    RoaringBitmapLenCodec, Search, StrBEU32Codec, U8StrStrCodec, BEU16, BEU32,
 };
 
 pub const DEFAULT_MIN_WORD_LEN_ONE_TYPO: u8 = 5;
@@ -55,6 +58,7 @@ pub mod main_key {
     pub const WORDS_PREFIXES_FST_KEY: &str = "words-prefixes-fst";
     pub const CREATED_AT_KEY: &str = "created-at";
     pub const UPDATED_AT_KEY: &str = "updated-at";
+    pub const SETTINGS_UPDATED_AT_KEY: &str = "settings-updated-at";
     pub const AUTHORIZE_TYPOS: &str = "authorize-typos";
     pub const ONE_TYPO_WORD_LEN: &str = "one-typo-word-len";
     pub const TWO_TYPOS_WORD_LEN: &str = "two-typos-word-len";
@@ -85,6 +89,31 @@ pub mod db_name {
     pub const DOCUMENTS: &str = "documents";
 }
 
+/// The size of the pages read by [`Index::warm_up`] to bring a value in the page cache.
+const WARM_UP_PAGE_SIZE: usize = 4096;
+
+/// A group of databases that [`Index::warm_up`] can load in the page cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarmUpTarget {
+    /// The databases used to find the documents matching the words of a query.
+    Words,
+    /// The documents themselves.
+    Documents,
+    /// The databases used to filter, sort and compute the facet distribution.
+    Facets,
+    All,
+}
+
+/// What has been done by an [`Index::warm_up`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WarmUpReport {
+    /// The number of bytes of keys and values that were read.
+    pub bytes: u64,
+    pub elapsed: Duration,
+    /// Whether the warm-up was stopped before reading every selected database.
+    pub aborted: bool,
+}
+
 #[derive(Clone)]
 pub struct Index {
     /// The LMDB environment which this index is associated with.
@@ -896,6 +925,11 @@ impl Index {
         }
     }
 
+    /// Returns the number of distinct words in the words dictionary of the engine.
+    pub fn number_of_words(&self, rtxn: &RoTxn) -> Result<u64> {
+        Ok(self.words_fst(rtxn)?.len() as u64)
+    }
+
     /* stop words */
 
     pub(crate) fn put_stop_words<A: AsRef<[u8]>>(
@@ -978,6 +1012,26 @@ impl Index {
         self.word_docids.remap_data_type::<RoaringBitmapLenCodec>().get(rtxn, word)
     }
 
+    /// Returns the number of times the given word appears in the documents of the index,
+    /// or 0 if the word is not indexed.
+    ///
+    /// The positions beyond the maximum number of positions indexed per attribute are not counted.
+    pub fn word_occurrences_count(&self, rtxn: &RoTxn, word: &str) -> Result<u64> {
+        let soft_deleted_documents = self.soft_deleted_documents_ids(rtxn)?;
+        let range = (word, u32::MIN)..=(word, u32::MAX);
+
+        let mut count = 0;
+        for result in self.word_position_docids.range(rtxn, &range)? {
+            let ((w, _position), docids) = result?;
+            // The range also contains the longer words starting with the requested one.
+            if w == word {
+                count += docids.difference_len(&soft_deleted_documents);
+            }
+        }
+
+        Ok(count)
+    }
+
     /* documents */
 
     /// Returns a [`Vec`] of the requested documents. Returns an error if a document is missing.
@@ -1020,6 +1074,49 @@ impl Index {
             }))
     }
 
+    /// Returns up to `n` documents chosen uniformly at random, restricted to the displayed fields.
+    ///
+    /// The internal ids are sampled with a reservoir before reading any document, so only the
+    /// sampled documents are decoded. The same `seed` returns the same sample as long as the
+    /// index doesn't change.
+    pub fn sample_documents(
+        &self,
+        rtxn: &RoTxn,
+        n: usize,
+        seed: Option<u64>,
+    ) -> Result<Vec<Object>> {
+        let documents_ids = self.documents_ids(rtxn)?;
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let mut sample = Vec::with_capacity(n.min(documents_ids.len() as usize));
+        for (i, id) in documents_ids.into_iter().enumerate() {
+            if i < n {
+                sample.push(id);
+            } else {
+                let j = rng.gen_range(0..=i);
+                if j < n {
+                    sample[j] = id;
+                }
+            }
+        }
+        // reading the documents in the order they are stored is faster
+        sample.sort_unstable();
+
+        let fields_ids_map = self.fields_ids_map(rtxn)?;
+        let displayed_fields = match self.displayed_fields_ids(rtxn)? {
+            Some(fields) => fields,
+            None => fields_ids_map.ids().collect(),
+        };
+
+        self.documents(rtxn, sample)?
+            .into_iter()
+            .map(|(_, obkv)| crate::obkv_to_json(&displayed_fields, &fields_ids_map, obkv))
+            .collect()
+    }
+
     pub fn facets_distribution<'a>(&'a self, rtxn: &'a RoTxn) -> FacetDistribution<'a> {
         FacetDistribution::new(rtxn, self)
     }
@@ -1058,6 +1155,24 @@ impl Index {
         self.main.put::<_, Str, SerdeJson<OffsetDateTime>>(wtxn, main_key::UPDATED_AT_KEY, time)
     }
 
+    /// Returns the last time the settings of the index were updated,
+    /// `None` if they were never updated since the index creation.
+    pub fn settings_updated_at(&self, rtxn: &RoTxn) -> heed::Result<Option<OffsetDateTime>> {
+        self.main.get::<_, Str, SerdeJson<OffsetDateTime>>(rtxn, main_key::SETTINGS_UPDATED_AT_KEY)
+    }
+
+    pub(crate) fn set_settings_updated_at(
+        &self,
+        wtxn: &mut RwTxn,
+        time: &OffsetDateTime,
+    ) -> heed::Result<()> {
+        self.main.put::<_, Str, SerdeJson<OffsetDateTime>>(
+            wtxn,
+            main_key::SETTINGS_UPDATED_AT_KEY,
+            time,
+        )
+    }
+
     pub fn authorize_typos(&self, txn: &RoTxn) -> heed::Result<bool> {
         // It is not possible to put a bool in heed with OwnedType, so we put a u8 instead. We
         // identify 0 as being false, and anything else as true. The absence of a value is true,
@@ -1187,6 +1302,70 @@ impl Index {
     pub(crate) fn delete_pagination_max_total_hits(&self, txn: &mut RwTxn) -> heed::Result<bool> {
         self.main.delete::<_, Str>(txn, main_key::PAGINATION_MAX_TOTAL_HITS)
     }
+
+    /// Reads through the databases selected by the targets so that the pages of the index are
+    /// in the page cache before the first searches need them.
+    ///
+    /// `should_abort` is called before reading every entry and stops the warm-up when it
+    /// returns `true`, the report then only accounts for what has been read.
+    pub fn warm_up(
+        &self,
+        rtxn: &RoTxn,
+        targets: &[WarmUpTarget],
+        should_abort: impl Fn() -> bool,
+    ) -> Result<WarmUpReport> {
+        let started_at = Instant::now();
+        let selected = |target| targets.contains(&target) || targets.contains(&WarmUpTarget::All);
+
+        let mut databases = Vec::new();
+        if selected(WarmUpTarget::Words) {
+            databases.extend([
+                self.word_docids.remap_types::<ByteSlice, ByteSlice>(),
+                self.exact_word_docids.remap_types(),
+                self.word_prefix_docids.remap_types(),
+                self.exact_word_prefix_docids.remap_types(),
+                self.docid_word_positions.remap_types(),
+                self.word_pair_proximity_docids.remap_types(),
+                self.word_prefix_pair_proximity_docids.remap_types(),
+                self.prefix_word_pair_proximity_docids.remap_types(),
+                self.word_position_docids.remap_types(),
+                self.field_id_word_count_docids.remap_types(),
+                self.word_prefix_position_docids.remap_types(),
+            ]);
+        }
+        if selected(WarmUpTarget::Documents) {
+            databases.push(self.documents.remap_types());
+        }
+        if selected(WarmUpTarget::Facets) {
+            databases.extend([
+                self.facet_id_exists_docids.remap_types::<ByteSlice, ByteSlice>(),
+                self.facet_id_f64_docids.remap_types(),
+                self.facet_id_string_docids.remap_types(),
+                self.field_id_docid_facet_f64s.remap_types(),
+                self.field_id_docid_facet_strings.remap_types(),
+            ]);
+        }
+
+        let mut report = WarmUpReport::default();
+        'databases: for database in databases {
+            for result in database.iter(rtxn)? {
+                if should_abort() {
+                    report.aborted = true;
+                    break 'databases;
+                }
+                let (key, value) = result?;
+                // Reading a byte of every page is enough for the kernel to load the entire page,
+                // values spanning multiple pages are stored in overflow pages that iterating does not touch.
+                let sum =
+                    value.iter().step_by(WARM_UP_PAGE_SIZE).fold(0u8, |s, b| s.wrapping_add(*b));
+                std::hint::black_box(sum);
+                report.bytes += (key.len() + value.len()) as u64;
+            }
+        }
+
+        report.elapsed = started_at.elapsed();
+        Ok(report)
+    }
 }
 
 #[cfg(test)]
@@ -1201,7 +1380,9 @@ pub(crate) mod tests {
 
     use crate::documents::DocumentsBatchReader;
     use crate::error::{Error, InternalError};
-    use crate::index::{DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS};
+    use crate::index::{
+        WarmUpTarget, DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS,
+    };
     use crate::update::{
         self, DeleteDocuments, DeletionStrategy, IndexDocuments, IndexDocumentsConfig,
         IndexDocumentsMethod, IndexerConfig, Settings,
@@ -2459,4 +2640,162 @@ pub(crate) mod tests {
 
         db_snap!(index, geo_faceted_documents_ids); // ensure that no documents were inserted
     }
+
+    #[test]
+    fn number_of_words() {
+        let index = TempIndex::new();
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.number_of_words(&rtxn).unwrap(), 0);
+        drop(rtxn);
+
+        index
+            .update_settings(|settings| {
+                settings.set_searchable_fields(vec![S("title")]);
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "the cat" },
+                { "id": 1, "title": "the dog" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.number_of_words(&rtxn).unwrap(), 3);
+    }
+
+    #[test]
+    fn sample_documents() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_displayed_fields(vec![S("title")]);
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "zero", "secret": 0 },
+                { "id": 1, "title": "one", "secret": 1 },
+                { "id": 2, "title": "two", "secret": 2 },
+                { "id": 3, "title": "three", "secret": 3 },
+                { "id": 4, "title": "four", "secret": 4 },
+                { "id": 5, "title": "five", "secret": 5 },
+                { "id": 6, "title": "six", "secret": 6 },
+                { "id": 7, "title": "seven", "secret": 7 },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+
+        let sample = index.sample_documents(&rtxn, 3, Some(42)).unwrap();
+        assert_eq!(sample.len(), 3);
+        assert_eq!(sample, index.sample_documents(&rtxn, 3, Some(42)).unwrap());
+        // only the displayed fields are returned
+        assert!(sample.iter().all(|document| document.keys().eq(["title"])));
+        let titles: HashSet<_> = sample.iter().map(|document| document["title"].as_str()).collect();
+        assert_eq!(titles.len(), 3);
+
+        // an index smaller than the sample is returned entirely
+        let sample = index.sample_documents(&rtxn, 20, None).unwrap();
+        assert_eq!(sample.len(), 8);
+        assert!(index.sample_documents(&rtxn, 0, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn word_occurrences_count() {
+        let mut index = TempIndex::new();
+        index.index_documents_config.deletion_strategy = DeletionStrategy::AlwaysSoft;
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "the cat and the dog", "text": "a cat" },
+                { "id": 1, "title": "cats and catalogs" },
+                { "id": 2, "title": "cat" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.word_occurrences_count(&rtxn, "cat").unwrap(), 3);
+        assert_eq!(index.word_occurrences_count(&rtxn, "the").unwrap(), 2);
+        assert_eq!(index.word_occurrences_count(&rtxn, "bird").unwrap(), 0);
+        drop(rtxn);
+
+        // soft deleted documents must not be counted
+        index.delete_document("2");
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.word_occurrences_count(&rtxn, "cat").unwrap(), 2);
+    }
+
+    #[test]
+    fn settings_updated_at() {
+        let index = TempIndex::new();
+
+        let rtxn = index.read_txn().unwrap();
+        let created_at = index.updated_at(&rtxn).unwrap();
+        assert_eq!(index.settings_updated_at(&rtxn).unwrap(), None);
+        drop(rtxn);
+
+        // setting the primary key, as the document additions do, is not a settings update
+        index
+            .update_settings(|settings| {
+                settings.set_primary_key(S("id"));
+            })
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.settings_updated_at(&rtxn).unwrap(), None);
+        drop(rtxn);
+
+        // a document update only moves the last update time
+        index.add_documents(documents!([{ "id": 0, "title": "cat" }])).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let documents_updated_at = index.updated_at(&rtxn).unwrap();
+        assert!(documents_updated_at > created_at);
+        assert_eq!(index.settings_updated_at(&rtxn).unwrap(), None);
+        drop(rtxn);
+
+        index
+            .update_settings(|settings| {
+                settings.set_searchable_fields(vec![S("title")]);
+            })
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let updated_at = index.updated_at(&rtxn).unwrap();
+        assert!(updated_at > documents_updated_at);
+        assert_eq!(index.settings_updated_at(&rtxn).unwrap(), Some(updated_at));
+    }
+
+    #[test]
+    fn warm_up() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("color") });
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "the quick brown fox", "color": "brown" },
+                { "id": 1, "title": "the lazy dog", "color": "black" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let words = index.warm_up(&rtxn, &[WarmUpTarget::Words], || false).unwrap();
+        let documents = index.warm_up(&rtxn, &[WarmUpTarget::Documents], || false).unwrap();
+        let facets = index.warm_up(&rtxn, &[WarmUpTarget::Facets], || false).unwrap();
+        assert!(words.bytes > 0 && documents.bytes > 0 && facets.bytes > 0);
+        assert!(!words.aborted && !documents.aborted && !facets.aborted);
+
+        let all = index.warm_up(&rtxn, &[WarmUpTarget::All], || false).unwrap();
+        assert_eq!(all.bytes, words.bytes + documents.bytes + facets.bytes);
+
+        let aborted = index.warm_up(&rtxn, &[WarmUpTarget::All], || true).unwrap();
+        assert_eq!(aborted.bytes, 0);
+        assert!(aborted.aborted);
+    }
 }
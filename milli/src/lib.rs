@@ -40,11 +40,11 @@ pub use self::heed_codec::{
     CboRoaringBitmapLenCodec, FieldIdWordCountCodec, ObkvCodec, RoaringBitmapCodec,
     RoaringBitmapLenCodec, StrBEU32Codec, U8StrStrCodec, UncheckedU8StrStrCodec,
 };
-pub use self::index::Index;
+pub use self::index::{Index, WarmUpReport, WarmUpTarget};
 pub use self::search::{
-    CriterionImplementationStrategy, FacetDistribution, Filter, FormatOptions, MatchBounds,
-    MatcherBuilder, MatchingWord, MatchingWords, Search, SearchResult, TermsMatchingStrategy,
-    DEFAULT_VALUES_PER_FACET,
+    CriterionImplementationStrategy, FacetDistribution, FacetHistogram, Filter, FormatOptions,
+    Histogram, MatchBounds, MatcherBuilder, MatchingWord, MatchingWords, Search, SearchResult,
+    TermsMatchingStrategy, DEFAULT_VALUES_PER_FACET,
 };
 
 pub type Result<T> = std::result::Result<T, error::Error>;
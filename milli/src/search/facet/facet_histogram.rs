@@ -0,0 +1,258 @@
+use std::num::NonZeroU32;
+
+use heed::types::{ByteSlice, DecodeIgnore};
+use roaring::RoaringBitmap;
+
+use crate::error::UserError;
+use crate::heed_codec::facet::{FacetGroupKeyCodec, FacetGroupValueCodec, OrderedF64Codec};
+use crate::{Index, Result};
+
+/// The distribution of the numeric values of a facet over equal-width buckets.
+///
+/// A document with several values for the facet is counted once per value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// The lowest value among the candidates, `0` when there is none.
+    pub min: f64,
+    /// The highest value among the candidates, `0` when there is none.
+    pub max: f64,
+    /// The width of every bucket, `0` when all the values are equal or when there is none.
+    pub bucket_width: f64,
+    /// The number of values in each bucket, the last one also contains `max`.
+    pub counts: Vec<u64>,
+}
+
+impl Histogram {
+    fn empty(buckets: usize) -> Histogram {
+        Histogram { min: 0.0, max: 0.0, bucket_width: 0.0, counts: vec![0; buckets] }
+    }
+}
+
+pub struct FacetHistogram<'a> {
+    field: String,
+    buckets: NonZeroU32,
+    candidates: Option<RoaringBitmap>,
+    rtxn: &'a heed::RoTxn<'a>,
+    index: &'a Index,
+}
+
+impl<'a> FacetHistogram<'a> {
+    pub fn new(
+        rtxn: &'a heed::RoTxn,
+        index: &'a Index,
+        field: impl Into<String>,
+        buckets: NonZeroU32,
+    ) -> FacetHistogram<'a> {
+        FacetHistogram { field: field.into(), buckets, candidates: None, rtxn, index }
+    }
+
+    /// Restricts the histogram to the given documents, e.g. the result of a filter.
+    pub fn candidates(&mut self, candidates: RoaringBitmap) -> &mut Self {
+        self.candidates = Some(candidates);
+        self
+    }
+
+    pub fn execute(&self) -> Result<Histogram> {
+        let filterable_fields = self.index.filterable_fields(self.rtxn)?;
+        if !crate::is_faceted(&self.field, &filterable_fields) {
+            return Err(UserError::InvalidFacetHistogramAttribute {
+                field: self.field.clone(),
+                valid_fields: filterable_fields.into_iter().collect(),
+            }
+            .into());
+        }
+
+        let buckets = self.buckets.get() as usize;
+        let fields_ids_map = self.index.fields_ids_map(self.rtxn)?;
+        let field_id = match fields_ids_map.id(&self.field) {
+            Some(field_id) => field_id,
+            // The field is filterable but no document contains it yet.
+            None => return Ok(Histogram::empty(buckets)),
+        };
+
+        let candidates = match &self.candidates {
+            Some(candidates) => candidates.clone(),
+            None => self.index.documents_ids(self.rtxn)?,
+        };
+
+        // The level 0 of the facet database contains every value of the field in ascending order.
+        let mut prefix = field_id.to_be_bytes().to_vec();
+        prefix.push(0);
+        let iter = self
+            .index
+            .facet_id_f64_docids
+            .as_polymorph()
+            .prefix_iter::<_, ByteSlice, ByteSlice>(self.rtxn, prefix.as_slice())?
+            .remap_types::<FacetGroupKeyCodec<OrderedF64Codec>, FacetGroupValueCodec>();
+
+        let mut has_numeric_values = false;
+        let mut values = Vec::new();
+        for result in iter {
+            let (key, value) = result?;
+            has_numeric_values = true;
+            let count = value.bitmap.intersection_len(&candidates);
+            if count != 0 {
+                values.push((key.left_bound, count));
+            }
+        }
+
+        // A field that only contains strings can't be bucketed.
+        if !has_numeric_values
+            && self
+                .index
+                .facet_id_string_docids
+                .as_polymorph()
+                .prefix_iter::<_, ByteSlice, DecodeIgnore>(self.rtxn, prefix.as_slice())?
+                .next()
+                .is_some()
+        {
+            return Err(UserError::NonNumericFacetHistogram { field: self.field.clone() }.into());
+        }
+
+        let (min, max) = match (values.first(), values.last()) {
+            (Some(&(min, _)), Some(&(max, _))) => (min, max),
+            _ => return Ok(Histogram::empty(buckets)),
+        };
+
+        let bucket_width = (max - min) / buckets as f64;
+        let mut counts = vec![0; buckets];
+        for (value, count) in values {
+            let bucket =
+                if bucket_width == 0.0 { 0 } else { ((value - min) / bucket_width) as usize };
+            counts[bucket.min(buckets - 1)] += count;
+        }
+
+        Ok(Histogram { min, max, bucket_width, counts })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use big_s::S;
+    use maplit::hashset;
+
+    use super::Histogram;
+    use crate::error::{Error, UserError};
+    use crate::index::tests::TempIndex;
+    use crate::{FacetHistogram, Filter};
+
+    fn buckets(n: u32) -> NonZeroU32 {
+        NonZeroU32::new(n).unwrap()
+    }
+
+    #[test]
+    fn histogram() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("price"), S("colour") })
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "price": 0, "colour": "blue" },
+                { "id": 1, "price": 10, "colour": "red" },
+                { "id": 2, "price": 24, "colour": "blue" },
+                { "id": 3, "price": 25, "colour": "red" },
+                { "id": 4, "price": [40, 99], "colour": "blue" },
+                { "id": 5, "price": 100, "colour": "red" },
+                { "id": 6, "colour": "red" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+
+        let histogram = FacetHistogram::new(&rtxn, &index, "price", buckets(4)).execute().unwrap();
+        assert_eq!(
+            histogram,
+            Histogram { min: 0.0, max: 100.0, bucket_width: 25.0, counts: vec![3, 2, 0, 2] }
+        );
+
+        let filter = Filter::from_str("colour = blue").unwrap().unwrap();
+        let candidates = filter.evaluate(&rtxn, &index).unwrap();
+        let histogram = FacetHistogram::new(&rtxn, &index, "price", buckets(2))
+            .candidates(candidates)
+            .execute()
+            .unwrap();
+        assert_eq!(
+            histogram,
+            Histogram { min: 0.0, max: 99.0, bucket_width: 49.5, counts: vec![3, 1] }
+        );
+    }
+
+    #[test]
+    fn histogram_of_strings() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("colour"), S("price") })
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "colour": "blue", "price": "cheap" },
+                { "id": 1, "colour": "red", "price": 12 },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+
+        let error = FacetHistogram::new(&rtxn, &index, "colour", buckets(2)).execute().unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UserError(UserError::NonNumericFacetHistogram { field }) if field == "colour"
+        ));
+
+        // the strings of a field that also contains numbers are ignored
+        let histogram = FacetHistogram::new(&rtxn, &index, "price", buckets(2)).execute().unwrap();
+        assert_eq!(
+            histogram,
+            Histogram { min: 12.0, max: 12.0, bucket_width: 0.0, counts: vec![1, 0] }
+        );
+    }
+
+    #[test]
+    fn histogram_degenerate_cases() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("price"), S("missing") })
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "price": 12 },
+                { "id": 1, "price": 12 },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+
+        let histogram = FacetHistogram::new(&rtxn, &index, "price", buckets(3)).execute().unwrap();
+        assert_eq!(
+            histogram,
+            Histogram { min: 12.0, max: 12.0, bucket_width: 0.0, counts: vec![2, 0, 0] }
+        );
+
+        let histogram = FacetHistogram::new(&rtxn, &index, "price", buckets(3))
+            .candidates(Default::default())
+            .execute()
+            .unwrap();
+        assert_eq!(
+            histogram,
+            Histogram { min: 0.0, max: 0.0, bucket_width: 0.0, counts: vec![0, 0, 0] }
+        );
+
+        let histogram =
+            FacetHistogram::new(&rtxn, &index, "missing", buckets(2)).execute().unwrap();
+        assert_eq!(histogram.counts, vec![0, 0]);
+
+        let error = FacetHistogram::new(&rtxn, &index, "id", buckets(2)).execute().unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UserError(UserError::InvalidFacetHistogramAttribute { .. })
+        ));
+    }
+}
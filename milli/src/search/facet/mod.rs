@@ -4,11 +4,13 @@ use heed::types::{ByteSlice, DecodeIgnore};
 use heed::{BytesDecode, RoTxn};
 
 pub use self::facet_distribution::{FacetDistribution, DEFAULT_VALUES_PER_FACET};
+pub use self::facet_histogram::{FacetHistogram, Histogram};
 pub use self::filter::{BadGeoError, Filter};
 use crate::heed_codec::facet::{FacetGroupKeyCodec, FacetGroupValueCodec};
 use crate::heed_codec::ByteSliceRefCodec;
 mod facet_distribution;
 mod facet_distribution_iter;
+mod facet_histogram;
 mod facet_range_search;
 mod facet_sort_ascending;
 mod facet_sort_descending;
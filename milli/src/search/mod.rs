@@ -15,7 +15,9 @@ use log::debug;
 use once_cell::sync::Lazy;
 use roaring::bitmap::RoaringBitmap;
 
-pub use self::facet::{FacetDistribution, Filter, DEFAULT_VALUES_PER_FACET};
+pub use self::facet::{
+    FacetDistribution, FacetHistogram, Filter, Histogram, DEFAULT_VALUES_PER_FACET,
+};
 use self::fst_utils::{Complement, Intersection, StartsWith, Union};
 pub use self::matches::{
     FormatOptions, MatchBounds, Matcher, MatcherBuilder, MatchingWord, MatchingWords,
@@ -45,8 +47,10 @@ pub struct Search<'a> {
     offset: usize,
     limit: usize,
     sort_criteria: Option<Vec<AscDesc>>,
+    distinct: Option<String>,
     terms_matching_strategy: TermsMatchingStrategy,
     authorize_typos: bool,
+    prefix_search: bool,
     words_limit: usize,
     exhaustive_number_hits: bool,
     criterion_implementation_strategy: CriterionImplementationStrategy,
@@ -62,8 +66,10 @@ impl<'a> Search<'a> {
             offset: 0,
             limit: 20,
             sort_criteria: None,
+            distinct: None,
             terms_matching_strategy: TermsMatchingStrategy::default(),
             authorize_typos: true,
+            prefix_search: true,
             exhaustive_number_hits: false,
             words_limit: 10,
             criterion_implementation_strategy: CriterionImplementationStrategy::default(),
@@ -92,6 +98,12 @@ impl<'a> Search<'a> {
         self
     }
 
+    /// Deduplicate the hits on this field instead of the distinct attribute of the index.
+    pub fn distinct(&mut self, field: impl Into<String>) -> &mut Search<'a> {
+        self.distinct = Some(field.into());
+        self
+    }
+
     pub fn terms_matching_strategy(&mut self, value: TermsMatchingStrategy) -> &mut Search<'a> {
         self.terms_matching_strategy = value;
         self
@@ -102,6 +114,12 @@ impl<'a> Search<'a> {
         self
     }
 
+    /// Whether the last word of the query can match any word starting with it.
+    pub fn prefix_search(&mut self, value: bool) -> &mut Search<'a> {
+        self.prefix_search = value;
+        self
+    }
+
     pub fn words_limit(&mut self, value: usize) -> &mut Search<'a> {
         self.words_limit = value;
         self
@@ -142,6 +160,7 @@ impl<'a> Search<'a> {
                 builder.terms_matching_strategy(self.terms_matching_strategy);
 
                 builder.authorize_typos(self.is_typo_authorized()?);
+                builder.prefix_search(self.prefix_search);
 
                 builder.words_limit(self.words_limit);
                 // We make sure that the analyzer is aware of the stop words
@@ -203,9 +222,25 @@ impl<'a> Search<'a> {
             return Err(UserError::SortRankingRuleMissing.into());
         }
 
+        // The distinct field given at search time must be faceted to be able
+        // to deduplicate the documents on its values.
+        let distinct_field = match self.distinct.as_deref() {
+            Some(field) => {
+                let faceted_fields = self.index.user_defined_faceted_fields(self.rtxn)?;
+                if !crate::is_faceted(field, &faceted_fields) {
+                    return Err(UserError::InvalidDistinctAttribute {
+                        field: field.to_string(),
+                        valid_fields: faceted_fields.into_iter().collect(),
+                    })?;
+                }
+                Some(field)
+            }
+            None => self.index.distinct_field(self.rtxn)?,
+        };
+
         let criteria_builder = criteria::CriteriaBuilder::new(self.rtxn, self.index)?;
 
-        match self.index.distinct_field(self.rtxn)? {
+        match distinct_field {
             None => {
                 let criteria = criteria_builder.build::<NoopDistinct>(
                     query_tree,
@@ -296,8 +331,10 @@ impl fmt::Debug for Search<'_> {
             offset,
             limit,
             sort_criteria,
+            distinct,
             terms_matching_strategy,
             authorize_typos,
+            prefix_search,
             words_limit,
             exhaustive_number_hits,
             criterion_implementation_strategy,
@@ -310,8 +347,10 @@ impl fmt::Debug for Search<'_> {
             .field("offset", offset)
             .field("limit", limit)
             .field("sort_criteria", sort_criteria)
+            .field("distinct", distinct)
             .field("terms_matching_strategy", terms_matching_strategy)
             .field("authorize_typos", authorize_typos)
+            .field("prefix_search", prefix_search)
             .field("exhaustive_number_hits", exhaustive_number_hits)
             .field("criterion_implementation_strategy", criterion_implementation_strategy)
             .field("words_limit", words_limit)
@@ -443,6 +482,9 @@ pub fn build_dfa(word: &str, typos: u8, is_prefix: bool) -> DFA {
 
 #[cfg(test)]
 mod test {
+    use big_s::S;
+    use maplit::hashset;
+
     use super::*;
     use crate::index::tests::TempIndex;
 
@@ -471,6 +513,63 @@ mod test {
         assert!(!search.is_typo_authorized().unwrap());
     }
 
+    #[test]
+    fn test_prefix_search() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "chat" },
+                { "id": 1, "text": "chateau" },
+            ]))
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+        let mut search = Search::new(&txn, &index);
+        search.query("chat");
+
+        // by default the last word of the query is a prefix
+        let SearchResult { mut documents_ids, .. } = search.execute().unwrap();
+        documents_ids.sort_unstable();
+        assert_eq!(documents_ids, vec![0, 1]);
+
+        search.prefix_search(false);
+        let SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids, vec![0]);
+    }
+
+    #[test]
+    fn test_distinct_at_search_time() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|s| {
+                s.set_filterable_fields(hashset! { S("color") });
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "chat", "color": "red" },
+                { "id": 1, "text": "chat", "color": "red" },
+                { "id": 2, "text": "chat", "color": "blue" },
+            ]))
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+        let mut search = Search::new(&txn, &index);
+        search.query("chat");
+        search.distinct("color");
+
+        let SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids.len(), 2);
+
+        // the distinct field must be faceted
+        search.distinct("text");
+        let error = search.execute().unwrap_err();
+        assert!(matches!(
+            error,
+            crate::Error::UserError(UserError::InvalidDistinctAttribute { .. })
+        ));
+    }
+
     #[test]
     fn test_one_typos_tolerance() {
         let fst = fst::Set::from_iter(["zealand"].iter()).unwrap().map_data(Cow::Owned).unwrap();
@@ -178,6 +178,7 @@ pub struct QueryTreeBuilder<'a> {
     index: &'a Index,
     terms_matching_strategy: TermsMatchingStrategy,
     authorize_typos: bool,
+    prefix_search: bool,
     words_limit: Option<usize>,
     exact_words: Option<fst::Set<Cow<'a, [u8]>>>,
 }
@@ -228,6 +229,7 @@ impl<'a> QueryTreeBuilder<'a> {
             index,
             terms_matching_strategy: TermsMatchingStrategy::default(),
             authorize_typos: true,
+            prefix_search: true,
             words_limit: None,
             exact_words: index.exact_words(rtxn)?,
         })
@@ -254,6 +256,15 @@ impl<'a> QueryTreeBuilder<'a> {
         self
     }
 
+    /// if `prefix_search` is set to `false` the last word of the query will
+    /// only match documents containing this exact word instead of any word
+    /// starting with it.
+    /// default value if not called: `true`
+    pub fn prefix_search(&mut self, prefix_search: bool) -> &mut Self {
+        self.prefix_search = prefix_search;
+        self
+    }
+
     /// Limit words and phrases that will be taken for query building.
     /// Any beyond `words_limit` will be ignored.
     pub fn words_limit(&mut self, words_limit: usize) -> &mut Self {
@@ -272,7 +283,7 @@ impl<'a> QueryTreeBuilder<'a> {
         &self,
         query: NormalizedTokenIter<A>,
     ) -> Result<Option<(Operation, PrimitiveQuery, MatchingWords)>> {
-        let primitive_query = create_primitive_query(query, self.words_limit);
+        let primitive_query = create_primitive_query(query, self.words_limit, self.prefix_search);
         if !primitive_query.is_empty() {
             let qt = create_query_tree(
                 self,
@@ -780,6 +791,7 @@ impl PrimitiveQueryPart {
 fn create_primitive_query<A>(
     query: NormalizedTokenIter<A>,
     words_limit: Option<usize>,
+    prefix_search: bool,
 ) -> PrimitiveQuery
 where
     A: AsRef<[u8]>,
@@ -801,7 +813,8 @@ where
             TokenKind::Word | TokenKind::StopWord => {
                 // 1. if the word is quoted we push it in a phrase-buffer waiting for the ending quote,
                 // 2. if the word is not the last token of the query and is not a stop_word we push it as a non-prefix word,
-                // 3. if the word is the last token of the query we push it as a prefix word.
+                // 3. if the word is the last token of the query we push it as a prefix word,
+                //    unless the prefix search is disabled.
                 if quoted {
                     if let TokenKind::StopWord = token.kind {
                         phrase.push(None)
@@ -815,7 +828,8 @@ where
                             .push(PrimitiveQueryPart::Word(token.lemma().to_string(), false));
                     }
                 } else {
-                    primitive_query.push(PrimitiveQueryPart::Word(token.lemma().to_string(), true));
+                    primitive_query
+                        .push(PrimitiveQueryPart::Word(token.lemma().to_string(), prefix_search));
                 }
             }
             TokenKind::Separator(separator_kind) => {
@@ -894,7 +908,7 @@ mod test {
             words_limit: Option<usize>,
             query: NormalizedTokenIter<A>,
         ) -> Result<Option<(Operation, PrimitiveQuery)>> {
-            let primitive_query = create_primitive_query(query, words_limit);
+            let primitive_query = create_primitive_query(query, words_limit, true);
             if !primitive_query.is_empty() {
                 let qt = create_query_tree(
                     self,
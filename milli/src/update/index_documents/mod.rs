@@ -19,10 +19,9 @@ use serde::{Deserialize, Serialize};
 use slice_group_by::GroupBy;
 use typed_chunk::{write_typed_chunk_into_index, TypedChunk};
 
-use self::enrich::enrich_documents_batch;
 pub use self::enrich::{
-    extract_finite_float_from_value, validate_document_id, validate_document_id_value,
-    validate_geo_from_json, DocumentId,
+    enrich_documents_batch, extract_finite_float_from_value, validate_document_id,
+    validate_document_id_value, validate_geo_from_json, DocumentId,
 };
 pub use self::helpers::{
     as_cloneable_grenad, create_sorter, create_writer, fst_stream_into_hashset,
@@ -1817,10 +1816,11 @@ mod tests {
             "branch_id_number": 0
         }]};
 
-        let Err(Error::UserError(UserError::MultiplePrimaryKeyCandidatesFound {
-            candidates
-        })) =
-            index.add_documents(doc_multiple_ids) else { panic!("Expected Error::UserError(MultiplePrimaryKeyCandidatesFound)") };
+        let Err(Error::UserError(UserError::MultiplePrimaryKeyCandidatesFound { candidates })) =
+            index.add_documents(doc_multiple_ids)
+        else {
+            panic!("Expected Error::UserError(MultiplePrimaryKeyCandidatesFound)")
+        };
 
         assert_eq!(candidates, vec![S("id"), S("project_id"), S("public_uid"),]);
 
@@ -690,12 +690,60 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         Ok(())
     }
 
+    /// Returns true if a setting other than the primary key is updated.
+    ///
+    /// The document additions and the index creations also use this builder to set the
+    /// primary key, which must not count as a settings update.
+    fn updates_more_than_primary_key(&self) -> bool {
+        let Settings {
+            wtxn: _,
+            index: _,
+            indexer_config: _,
+            primary_key: _,
+            searchable_fields,
+            displayed_fields,
+            filterable_fields,
+            sortable_fields,
+            criteria,
+            stop_words,
+            distinct_field,
+            synonyms,
+            authorize_typos,
+            min_word_len_two_typos,
+            min_word_len_one_typo,
+            exact_words,
+            exact_attributes,
+            max_values_per_facet,
+            pagination_max_total_hits,
+        } = self;
+
+        !(searchable_fields.is_not_set()
+            && displayed_fields.is_not_set()
+            && filterable_fields.is_not_set()
+            && sortable_fields.is_not_set()
+            && criteria.is_not_set()
+            && stop_words.is_not_set()
+            && distinct_field.is_not_set()
+            && synonyms.is_not_set()
+            && authorize_typos.is_not_set()
+            && min_word_len_two_typos.is_not_set()
+            && min_word_len_one_typo.is_not_set()
+            && exact_words.is_not_set()
+            && exact_attributes.is_not_set()
+            && max_values_per_facet.is_not_set()
+            && pagination_max_total_hits.is_not_set())
+    }
+
     pub fn execute<FP, FA>(mut self, progress_callback: FP, should_abort: FA) -> Result<()>
     where
         FP: Fn(UpdateIndexingStep) + Sync,
         FA: Fn() -> bool + Sync,
     {
-        self.index.set_updated_at(self.wtxn, &OffsetDateTime::now_utc())?;
+        let now = OffsetDateTime::now_utc();
+        self.index.set_updated_at(self.wtxn, &now)?;
+        if self.updates_more_than_primary_key() {
+            self.index.set_settings_updated_at(self.wtxn, &now)?;
+        }
 
         let old_faceted_fields = self.index.user_defined_faceted_fields(self.wtxn)?;
         let old_fields_ids_map = self.index.fields_ids_map(self.wtxn)?;